@@ -225,6 +225,11 @@ pub struct NodeConfig {
     /// Configuration for the transaction driver.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub transaction_driver_config: Option<TransactionDriverConfig>,
+
+    /// Runs a background task that periodically checks that the native transfers in newly
+    /// executed checkpoints conserve value. Off by default.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub enable_native_transfer_reconciler: bool,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -1328,6 +1333,17 @@ pub struct AuthorityOverloadConfig {
     // is above the threshold.
     #[serde(default = "default_max_transaction_manager_per_object_queue_length")]
     pub max_transaction_manager_per_object_queue_length: usize,
+
+    // When set to true, all new native transfers are rejected while the validator is
+    // overloaded, instead of only the sampled load shedding percentage. Native transfers
+    // pay no gas, so they are the first work to defer.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub shed_native_transfers_when_overloaded: bool,
+
+    // When set, new native transfers are rejected from this long before the expected end of the
+    // epoch until reconfiguration, and clients are told to retry in the next epoch.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub native_transfer_epoch_end_window: Option<Duration>,
 }
 
 fn default_max_txn_age_in_queue() -> Duration {
@@ -1386,6 +1402,8 @@ impl Default for AuthorityOverloadConfig {
             max_transaction_manager_queue_length: default_max_transaction_manager_queue_length(),
             max_transaction_manager_per_object_queue_length:
                 default_max_transaction_manager_per_object_queue_length(),
+            shed_native_transfers_when_overloaded: false,
+            native_transfer_epoch_end_window: None,
         }
     }
 }
@@ -10,7 +10,9 @@ use rand::seq::SliceRandom;
 use futures::future::join_all;
 use prometheus::Registry;
 use std::sync::Arc;
-use sui_core::test_utils::{make_cert_with_large_committee, make_dummy_tx};
+use sui_core::test_utils::{
+    make_cert_with_large_committee, make_dummy_native_transfer_tx, make_dummy_tx,
+};
 use sui_types::committee::Committee;
 use sui_types::crypto::{AccountKeyPair, AuthorityKeyPair, get_key_pair};
 use sui_types::in_memory_storage::InMemoryStorage;
@@ -152,5 +154,55 @@ fn batch_verification_bench(c: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(benches, batch_verification_bench, async_verifier_bench);
+fn native_transfer_verification_bench(c: &mut Criterion) {
+    let (committee, _) = Committee::new_simple_test_committee_of_size(4);
+    let registry = Registry::new();
+    let metrics = SignatureVerifierMetrics::new(&registry);
+    let verifier = SignatureVerifier::new(
+        Arc::new(committee),
+        Arc::new(InMemoryStorage::new(vec![])),
+        metrics,
+        vec![],
+        ZkLoginEnv::Test,
+        true,
+        true,
+        true,
+        Some(30),
+        true,
+        true,
+    );
+
+    let mut group = c.benchmark_group("native_transfer_verify");
+    for batch_size in [1, 16, 64, 256] {
+        let (receiver, _): (_, AccountKeyPair) = get_key_pair();
+        let txs: Vec<_> = (0..batch_size)
+            .map(|_| {
+                let (sender, sender_sec): (_, AccountKeyPair) = get_key_pair();
+                make_dummy_native_transfer_tx(receiver, sender, &sender_sec)
+            })
+            .collect();
+        let txs = txs.iter().map(|tx| tx.data()).collect_vec();
+
+        group.throughput(Throughput::Elements(batch_size));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(format!("size={}", batch_size)),
+            &batch_size,
+            |b, _| {
+                b.iter(|| {
+                    // Skip the verified-signature cache so every iteration does the work.
+                    verifier.clear_signature_cache();
+                    verifier.par_verify_native_transfers(&txs);
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    batch_verification_bench,
+    async_verifier_bench,
+    native_transfer_verification_bench
+);
 criterion_main!(benches);
@@ -0,0 +1,187 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Compares the execution latency of a native transfer against the equivalent Move transfer (a
+//! `SplitCoins` + `TransferObjects` PTB). Both are executed with `try_execute_immediately` on a
+//! single authority, so only execution is measured.
+
+use criterion::*;
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use sui_core::authority::AuthorityState;
+use sui_core::authority::ExecutionEnv;
+use sui_core::authority::test_authority_builder::TestAuthorityBuilder;
+use sui_core::test_utils::{make_native_transfer_tx, protocol_config_v2};
+use sui_types::base_types::{ObjectRef, SuiAddress};
+use sui_types::crypto::{AccountKeyPair, get_key_pair};
+use sui_types::effects::TransactionEffectsAPI;
+use sui_types::executable_transaction::VerifiedExecutableTransaction;
+use sui_types::transaction::{
+    TEST_ONLY_GAS_UNIT_FOR_TRANSFER, TransactionData, VerifiedTransaction,
+};
+use sui_types::utils::to_sender_signed_transaction;
+use tokio::runtime::Runtime;
+
+/// Number of transfers of each kind used for the p50/p99 comparison.
+const LATENCY_ITERATIONS: usize = 500;
+
+const TRANSFER_AMOUNT: u64 = 1;
+const COIN_BALANCE: u64 = 1_000_000_000_000_000;
+
+struct TransferBench {
+    state: Arc<AuthorityState>,
+    sender: SuiAddress,
+    sender_key: AccountKeyPair,
+    recipient: SuiAddress,
+    native_coin: ObjectRef,
+    move_gas_coin: ObjectRef,
+    rgp: u64,
+}
+
+impl TransferBench {
+    async fn new() -> Self {
+        let (sender, sender_key): (_, AccountKeyPair) = get_key_pair();
+        // Each transfer mutates its coin, so a single large coin per path lasts for any number
+        // of iterations.
+        let (builder, coins) = TestAuthorityBuilder::new()
+            .with_protocol_config(protocol_config_v2())
+            .with_gas_coins(sender, &[COIN_BALANCE, COIN_BALANCE]);
+        let state = builder.build().await;
+        let rgp = state.reference_gas_price_for_testing().unwrap();
+        Self {
+            state,
+            sender,
+            sender_key,
+            recipient: SuiAddress::random_for_testing_only(),
+            native_coin: coins[0],
+            move_gas_coin: coins[1],
+            rgp,
+        }
+    }
+
+    async fn native_transfer(&mut self) -> Duration {
+        let tx = make_native_transfer_tx(
+            &self.sender_key,
+            self.native_coin,
+            self.recipient,
+            TRANSFER_AMOUNT,
+        );
+        let (latency, coin) = self.execute(tx, self.native_coin).await;
+        self.native_coin = coin;
+        latency
+    }
+
+    async fn move_transfer(&mut self) -> Duration {
+        let data = TransactionData::new_transfer_sui(
+            self.recipient,
+            self.sender,
+            Some(TRANSFER_AMOUNT),
+            self.move_gas_coin,
+            self.rgp * TEST_ONLY_GAS_UNIT_FOR_TRANSFER,
+            self.rgp,
+        );
+        let tx = VerifiedTransaction::new_unchecked(to_sender_signed_transaction(
+            data,
+            &self.sender_key,
+        ));
+        let (latency, coin) = self.execute(tx, self.move_gas_coin).await;
+        self.move_gas_coin = coin;
+        latency
+    }
+
+    /// Executes `tx` and returns its latency and the new reference of `coin`.
+    async fn execute(&self, tx: VerifiedTransaction, coin: ObjectRef) -> (Duration, ObjectRef) {
+        let cert = VerifiedExecutableTransaction::new_from_quorum_execution(tx, 0);
+        let epoch_store = self.state.epoch_store_for_testing();
+
+        let start = Instant::now();
+        let (effects, _) = self
+            .state
+            .try_execute_immediately(&cert, ExecutionEnv::new(), &epoch_store)
+            .await
+            .unwrap();
+        let latency = start.elapsed();
+
+        assert!(effects.status().is_ok(), "{:?}", effects.status());
+        let (coin, _) = effects
+            .mutated()
+            .into_iter()
+            .find(|(object_ref, _)| object_ref.0 == coin.0)
+            .unwrap();
+        (latency, coin)
+    }
+}
+
+fn percentile(sorted: &[Duration], p: usize) -> Duration {
+    sorted[(sorted.len() - 1) * p / 100]
+}
+
+fn report(name: &str, mut latencies: Vec<Duration>) -> Duration {
+    latencies.sort();
+    let p50 = percentile(&latencies, 50);
+    let p99 = percentile(&latencies, 99);
+    println!(
+        "{name}: p50 = {p50:?}, p99 = {p99:?} over {} transfers",
+        latencies.len()
+    );
+    p50
+}
+
+fn compare_latency(runtime: &Runtime, bench: &mut TransferBench) {
+    let (native, move_) = runtime.block_on(async {
+        let mut native = Vec::with_capacity(LATENCY_ITERATIONS);
+        let mut move_ = Vec::with_capacity(LATENCY_ITERATIONS);
+        // Interleave the two paths so that neither benefits from a warmer cache.
+        for _ in 0..LATENCY_ITERATIONS {
+            native.push(bench.native_transfer().await);
+            move_.push(bench.move_transfer().await);
+        }
+        (native, move_)
+    });
+
+    let native_p50 = report("native transfer", native);
+    let move_p50 = report("move transfer", move_);
+    println!(
+        "native/move p50 ratio = {:.2}",
+        native_p50.as_secs_f64() / move_p50.as_secs_f64()
+    );
+}
+
+fn native_transfer_bench(c: &mut Criterion) {
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+    let mut bench = runtime.block_on(TransferBench::new());
+
+    compare_latency(&runtime, &mut bench);
+
+    let mut group = c.benchmark_group("transfer_latency");
+    group.bench_function("native", |b| {
+        b.iter_custom(|iters| {
+            runtime.block_on(async {
+                let mut total = Duration::ZERO;
+                for _ in 0..iters {
+                    total += bench.native_transfer().await;
+                }
+                total
+            })
+        })
+    });
+    group.bench_function("move", |b| {
+        b.iter_custom(|iters| {
+            runtime.block_on(async {
+                let mut total = Duration::ZERO;
+                for _ in 0..iters {
+                    total += bench.move_transfer().await;
+                }
+                total
+            })
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, native_transfer_bench);
+criterion_main!(benches);
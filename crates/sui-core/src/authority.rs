@@ -24,6 +24,7 @@ use authority_per_epoch_store::CertLockGuard;
 use fastcrypto::encoding::Base58;
 use fastcrypto::encoding::Encoding;
 use fastcrypto::hash::MultisetHash;
+use futures::{Stream, StreamExt, future};
 use itertools::Itertools;
 use move_binary_format::CompiledModule;
 use move_binary_format::binary_config::BinaryConfig;
@@ -71,7 +72,7 @@ use sui_types::execution::ExecutionTiming;
 use sui_types::execution_params::BalanceWithdrawStatus;
 use sui_types::execution_params::ExecutionOrEarlyError;
 use sui_types::execution_params::get_early_execution_error;
-use sui_types::execution_status::ExecutionStatus;
+use sui_types::execution_status::{ExecutionFailureStatus, ExecutionStatus};
 use sui_types::inner_temporary_store::PackageStoreWithFallback;
 use sui_types::layout_resolver::LayoutResolver;
 use sui_types::layout_resolver::into_struct_layout;
@@ -128,6 +129,7 @@ use sui_types::error::{ExecutionError, SuiErrorKind, UserInputError};
 use sui_types::event::{Event, EventID};
 use sui_types::executable_transaction::VerifiedExecutableTransaction;
 use sui_types::gas::{GasCostSummary, SuiGasStatus};
+use sui_types::gas_coin::GasCoin;
 use sui_types::inner_temporary_store::{
     InnerTemporaryStore, ObjectMap, TemporaryModuleResolver, TxCoins, WrittenObjects,
 };
@@ -143,6 +145,9 @@ use sui_types::messages_grpc::{
     ObjectInfoResponse, TransactionInfoRequest, TransactionInfoResponse, TransactionStatus,
 };
 use sui_types::metrics::{BytecodeVerifierMetrics, LimitsMetrics};
+use sui_types::native_transfer::{
+    NativeTransferLineageEntry, NativeTransferPage, NativeTransferSortKey, NativeTransferSummary,
+};
 use sui_types::object::{MoveObject, OBJECT_START_VERSION, Owner, PastObjectRead};
 use sui_types::storage::{
     BackingPackageStore, BackingStore, ObjectKey, ObjectOrTombstone, ObjectStore, WriteKind,
@@ -182,7 +187,9 @@ use crate::global_state_hasher::{GlobalStateHashStore, GlobalStateHasher, Wrappe
 use crate::metrics::LatencyObserver;
 use crate::metrics::RateTracker;
 use crate::module_cache_metrics::ResolverMetrics;
-use crate::overload_monitor::{AuthorityOverloadInfo, overload_monitor_accept_tx};
+use crate::overload_monitor::{
+    AuthorityOverloadInfo, is_within_epoch_end_window, overload_monitor_accept_tx,
+};
 use crate::stake_aggregator::StakeAggregator;
 use crate::subscription_handler::SubscriptionHandler;
 use crate::transaction_input_loader::TransactionInputLoader;
@@ -310,6 +317,11 @@ pub struct AuthorityMetrics {
 
     pub(crate) transaction_overload_sources: IntCounterVec,
 
+    pub(crate) native_transfer_conservation_violations: IntCounter,
+    pub(crate) native_transfers_total: IntCounter,
+    pub(crate) native_transfers_failed_total: IntCounterVec,
+    pub(crate) native_transfer_amount: Histogram,
+
     /// Post processing metrics
     post_processing_total_events_emitted: IntCounter,
     post_processing_total_tx_indexed: IntCounter,
@@ -394,8 +406,18 @@ const GAS_LATENCY_RATIO_BUCKETS: &[f64] = &[
     3000.0, 4000.0, 5000.0, 6000.0, 7000.0, 8000.0, 9000.0, 10000.0, 50000.0, 100000.0, 1000000.0,
 ];
 
+// Buckets for native transfer amounts in MIST, from 1 MIST to 1B SUI.
+const NATIVE_TRANSFER_AMOUNT_BUCKETS: &[f64] = &[
+    1., 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8, 1e9, 1e10, 1e11, 1e12, 1e13, 1e14, 1e15, 1e16,
+    1e17, 1e18,
+];
+
 pub const DEV_INSPECT_GAS_COIN_VALUE: u64 = 1_000_000_000_000_000_000;
 
+/// The most checkpoints a single call to `get_native_transfers_page` reads, so its cost stays
+/// bounded however few native transfers the chain holds.
+const MAX_NATIVE_TRANSFER_PAGE_CHECKPOINTS: u64 = 1_000;
+
 // Transaction author should have observed the input objects as finalized output,
 // so usually the wait does not need to be long.
 // When submitted by TransactionDriver, it will retry quickly if there is no return from this validator too.
@@ -584,6 +606,32 @@ impl AuthorityMetrics {
                 &["source"],
                 registry)
             .unwrap(),
+            native_transfer_conservation_violations: register_int_counter_with_registry!(
+                "native_transfer_conservation_violations",
+                "Number of native transfers whose source coin lost a different amount than the recipient received.",
+                registry,
+            )
+            .unwrap(),
+            native_transfers_total: register_int_counter_with_registry!(
+                "native_transfers_total",
+                "Number of native transactions executed, successful or not.",
+                registry,
+            )
+            .unwrap(),
+            native_transfers_failed_total: register_int_counter_vec_with_registry!(
+                "native_transfers_failed_total",
+                "Number of native transactions that failed execution, by failure reason.",
+                &["reason"],
+                registry,
+            )
+            .unwrap(),
+            native_transfer_amount: register_histogram_with_registry!(
+                "native_transfer_amount",
+                "Distribution of amounts in MIST sent to each recipient by successful native transfers.",
+                NATIVE_TRANSFER_AMOUNT_BUCKETS.to_vec(),
+                registry,
+            )
+            .unwrap(),
             execution_driver_executed_transactions: register_int_counter_with_registry!(
                 "execution_driver_executed_transactions",
                 "Cumulative number of transaction executed by execution driver",
@@ -1015,6 +1063,10 @@ impl AuthorityState {
         let tx_digest = transaction.digest();
         let tx_data = transaction.data().transaction_data();
 
+        if tx_data.kind().is_unmetered() {
+            self.check_native_transfer_epoch_end_window(epoch_store)?;
+        }
+
         let input_object_kinds = tx_data.input_objects()?;
         let receiving_objects_refs = tx_data.receiving_objects();
 
@@ -1380,11 +1432,42 @@ impl AuthorityState {
         Ok(())
     }
 
+    /// Turns away native transfers that arrive within the configured window before the expected
+    /// end of the epoch, so that clients retry them once the next epoch has started.
+    fn check_native_transfer_epoch_end_window(
+        &self,
+        epoch_store: &AuthorityPerEpochStore,
+    ) -> SuiResult {
+        let Some(window) = self.overload_config().native_transfer_epoch_end_window else {
+            return Ok(());
+        };
+        let epoch_start_state = epoch_store.epoch_start_state();
+        if is_within_epoch_end_window(
+            Self::unixtime_now_ms(),
+            epoch_start_state.epoch_start_timestamp_ms(),
+            epoch_start_state.epoch_duration_ms(),
+            window,
+        ) {
+            return Err(SuiErrorKind::ValidatorHaltedAtEpochEnd.into());
+        }
+        Ok(())
+    }
+
     fn check_authority_overload(&self, tx_data: &SenderSignedData) -> SuiResult {
         if !self.overload_info.is_overload.load(Ordering::Relaxed) {
             return Ok(());
         }
 
+        if self.overload_config().shed_native_transfers_when_overloaded
+            && tx_data.transaction_data().kind().is_unmetered()
+        {
+            // Ask the client to come back once the overload monitor has re-evaluated.
+            return Err(SuiErrorKind::ValidatorOverloadedRetryAfter {
+                retry_after_secs: self.overload_config().overload_monitor_interval.as_secs(),
+            }
+            .into());
+        }
+
         let load_shedding_percentage = self
             .overload_info
             .load_shedding_percentage
@@ -1696,6 +1779,7 @@ impl AuthorityState {
         debug!(
             ?tx_digest,
             fx_digest=?effects.digest(),
+            ?scheduling_source,
             "process_certificate succeeded in {:.3}ms",
             (execution_start_time.elapsed().as_micros() as f64) / 1000.0
         );
@@ -1765,6 +1849,48 @@ impl AuthorityState {
         ExecutionOutput::Success((effects, execution_error_opt))
     }
 
+    /// Executes a stream of native transfers on the fast path, one at a time and in stream order,
+    /// yielding each transaction's effects as soon as it executes. A transfer may therefore spend
+    /// a coin created or mutated by an earlier transfer in the stream. The next transaction is
+    /// only pulled once the consumer polls for more, so a slow consumer slows down execution.
+    ///
+    /// Transfers that abort still yield their effects, with a failed execution status. The stream
+    /// ends early if a transaction cannot execute at all, e.g. because the epoch ended.
+    pub fn execute_native_transfers_stream(
+        &self,
+        txs: impl Stream<Item = VerifiedExecutableTransaction>,
+    ) -> impl Stream<Item = (TransactionDigest, TransactionEffects)> {
+        let epoch_store = self.load_epoch_store_one_call_per_task().clone();
+        txs.then(move |tx| {
+            let epoch_store = epoch_store.clone();
+            async move {
+                let output = self
+                    .try_execute_immediately(
+                        &tx,
+                        ExecutionEnv::new()
+                            .with_scheduling_source(SchedulingSource::MysticetiFastPath),
+                        &epoch_store,
+                    )
+                    .await;
+                (*tx.digest(), output)
+            }
+        })
+        .scan((), |_, (tx_digest, output)| {
+            future::ready(match output {
+                ExecutionOutput::Success((effects, _)) => Some((tx_digest, effects)),
+                ExecutionOutput::EpochEnded
+                | ExecutionOutput::Fatal(_)
+                | ExecutionOutput::RetryLater => {
+                    warn!(
+                        ?tx_digest,
+                        "Stopping native transfer stream: transaction did not execute"
+                    );
+                    None
+                }
+            })
+        })
+    }
+
     pub fn read_objects_for_execution(
         &self,
         tx_lock: &CertLockGuard,
@@ -2009,6 +2135,42 @@ impl AuthorityState {
                 .kind()
                 .num_commands() as f64,
         );
+
+        let kind = certificate.transaction_data().kind();
+        if kind.is_unmetered() {
+            self.update_native_transfer_metrics(kind, effects);
+        }
+    }
+
+    fn update_native_transfer_metrics(&self, kind: &TransactionKind, effects: &TransactionEffects) {
+        self.metrics.native_transfers_total.inc();
+        if let ExecutionStatus::Failure { error, .. } = effects.status() {
+            let reason: &'static str = error.into();
+            self.metrics
+                .native_transfers_failed_total
+                .with_label_values(&[reason])
+                .inc();
+            return;
+        }
+
+        // Only record amounts the transaction names. A top-up, merge or whole-coin transfer
+        // moves an amount that depends on coin balances.
+        let amounts: Vec<u64> = match kind {
+            TransactionKind::NativeTransfer(transfer)
+            | TransactionKind::NativeTransferWithConsent(transfer) => vec![transfer.amount],
+            TransactionKind::NativeTransferWithChange(transfer) => vec![transfer.amount],
+            TransactionKind::NativeTransferAfterEpoch(transfer) => vec![transfer.amount],
+            TransactionKind::NativeTransferInto(transfer) => vec![transfer.amount],
+            TransactionKind::NativeMultiTransfer(transfer) => transfer
+                .recipients
+                .iter()
+                .map(|(_, amount)| *amount)
+                .collect(),
+            _ => vec![],
+        };
+        for amount in amounts {
+            self.metrics.native_transfer_amount.observe(amount as f64);
+        }
     }
 
     /// execute_certificate validates the transaction input, and executes the certificate,
@@ -2490,6 +2652,97 @@ impl AuthorityState {
         ))
     }
 
+    /// Previews a native transfer by running the signing-time checks and executing it against the
+    /// current state, without writing anything to storage.
+    ///
+    /// Returns the summary of the transfer as it would execute now, including the derived
+    /// created-coin ID, or the `ExecutionFailureStatus` a real execution would abort with. Errors
+    /// are returned for transactions that would be rejected before execution.
+    #[instrument(level = "trace", skip_all)]
+    pub fn dry_run_native_transfer(
+        &self,
+        tx_data: &TransactionData,
+    ) -> SuiResult<Result<NativeTransferSummary, ExecutionFailureStatus>> {
+        if !matches!(
+            tx_data.kind(),
+            TransactionKind::NativeTransfer(_) | TransactionKind::NativeTransferWithConsent(_)
+        ) {
+            return Err(SuiErrorKind::UnsupportedFeatureError {
+                error: "native transfer dry-run only supports native transfers".to_string(),
+            }
+            .into());
+        }
+
+        let epoch_store = self.load_epoch_store_one_call_per_task();
+        let protocol_config = epoch_store.protocol_config();
+        tx_data.validity_check(protocol_config)?;
+
+        let input_object_kinds = tx_data.input_objects()?;
+        let (input_objects, receiving_objects) = self.input_loader.read_objects_for_signing(
+            // We don't want to cache this transaction since it's a dry run.
+            None,
+            &input_object_kinds,
+            &[],
+            epoch_store.epoch(),
+        )?;
+        let (gas_status, checked_input_objects) = sui_transaction_checks::check_transaction_input(
+            protocol_config,
+            epoch_store.reference_gas_price(),
+            tx_data,
+            input_objects,
+            &receiving_objects,
+            &self.metrics.bytecode_verifier_metrics,
+            &self.config.verifier_signing_config,
+        )?;
+
+        let transaction_digest = tx_data.digest();
+        let execution_params = match get_early_execution_error(
+            &transaction_digest,
+            &checked_input_objects,
+            self.config.certificate_deny_config.certificate_deny_set(),
+            &BalanceWithdrawStatus::NoWithdraw,
+        ) {
+            Some(error) => ExecutionOrEarlyError::Err(error),
+            None => ExecutionOrEarlyError::Ok(()),
+        };
+
+        let executor = sui_execution::executor(protocol_config, true)
+            .expect("Creating an executor should not fail here");
+        let (kind, signer, gas_data) = tx_data.execution_parts();
+        let (inner_temp_store, _, effects, _timings, execution_result) = executor
+            .execute_transaction_to_effects(
+                self.get_backing_store().as_ref(),
+                protocol_config,
+                self.metrics.limits_metrics.clone(),
+                false,
+                execution_params,
+                &epoch_store.epoch_start_config().epoch_data().epoch_id(),
+                epoch_store
+                    .epoch_start_config()
+                    .epoch_data()
+                    .epoch_start_timestamp(),
+                checked_input_objects,
+                gas_data,
+                gas_status,
+                kind,
+                signer,
+                transaction_digest,
+                &mut None,
+            );
+        if let Err(error) = execution_result {
+            return Ok(Err(error.kind().clone()));
+        }
+
+        let output_objects: Vec<_> = inner_temp_store.written.into_values().collect();
+        let summary =
+            NativeTransferSummary::new(tx_data, &effects, &output_objects).ok_or_else(|| {
+                SuiError::from(SuiErrorKind::Unknown(format!(
+                    "Dry-run of native transfer {transaction_digest} did not write both coins"
+                )))
+            })?;
+        Ok(Ok(summary))
+    }
+
     pub fn simulate_transaction(
         &self,
         mut transaction: TransactionData,
@@ -3322,6 +3575,17 @@ impl AuthorityState {
                 .tap_err(|e| error!(?tx_digest, "Post processing - Couldn't index tx: {e}"))
                 .expect("Indexing tx should not fail");
 
+            if matches!(
+                certificate.transaction_data().kind(),
+                TransactionKind::NativeTransfer(_) | TransactionKind::NativeTransferWithConsent(_)
+            ) && let Some(summary) = NativeTransferSummary::new(
+                certificate.transaction_data(),
+                effects,
+                &written.values().cloned().collect::<Vec<_>>(),
+            ) {
+                self.subscription_handler.process_native_transfer(summary);
+            }
+
             let effects: SuiTransactionBlockEffects = effects.clone().try_into()?;
             let events = self.make_transaction_block_events(
                 events.clone(),
@@ -4567,6 +4831,238 @@ impl AuthorityState {
             .map_err(Into::into)
     }
 
+    /// Returns a summary for each of `digests`, in input order. An entry is `None` if the digest
+    /// is unknown, is not a `NativeTransfer` or `NativeTransferWithConsent`, did not execute
+    /// successfully, or its output coins are no longer available. Other native kinds have no
+    /// `NativeTransferSummary`, so they are reported as `None` too.
+    ///
+    /// With `include_source_balances`, each summary also carries the source coin's balance before
+    /// and after the transfer, read from the version the transfer consumed. An entry is then also
+    /// `None` if that version is no longer available.
+    #[instrument(level = "trace", skip_all)]
+    pub fn get_native_transfer_effects_batch(
+        &self,
+        digests: Vec<TransactionDigest>,
+        include_source_balances: bool,
+    ) -> Vec<Option<NativeTransferSummary>> {
+        let cache_reader = self.get_transaction_cache_reader();
+        let transactions = cache_reader.multi_get_transaction_blocks(&digests);
+        let effects = cache_reader.multi_get_executed_effects(&digests);
+
+        let transfers: Vec<_> = transactions
+            .into_iter()
+            .zip(effects)
+            .map(|(transaction, effects)| match (transaction, effects) {
+                (Some(transaction), Some(effects))
+                    if matches!(
+                        transaction.transaction_data().kind(),
+                        TransactionKind::NativeTransfer(_)
+                            | TransactionKind::NativeTransferWithConsent(_)
+                    ) && effects.status().is_ok() =>
+                {
+                    Some((transaction, effects))
+                }
+                _ => None,
+            })
+            .collect();
+
+        // Read the output objects of every transfer in the batch with a single multi-get.
+        let object_keys: Vec<_> = transfers
+            .iter()
+            .flatten()
+            .flat_map(|(_, effects)| {
+                effects
+                    .all_changed_objects()
+                    .into_iter()
+                    .map(|(object_ref, _owner, _kind)| ObjectKey::from(object_ref))
+            })
+            .collect();
+        let mut objects = self
+            .get_object_cache_reader()
+            .multi_get_objects_by_key(&object_keys)
+            .into_iter();
+
+        // A transfer consumes its source coin at the version named in the transaction.
+        let source_keys: Vec<_> = transfers
+            .iter()
+            .flatten()
+            .filter(|_| include_source_balances)
+            .filter_map(
+                |(transaction, _)| match transaction.transaction_data().kind() {
+                    TransactionKind::NativeTransfer(transfer)
+                    | TransactionKind::NativeTransferWithConsent(transfer) => {
+                        Some(ObjectKey::from(transfer.coin))
+                    }
+                    _ => None,
+                },
+            )
+            .collect();
+        let mut sources = self
+            .get_object_cache_reader()
+            .multi_get_objects_by_key(&source_keys)
+            .into_iter();
+
+        transfers
+            .into_iter()
+            .map(|transfer| {
+                let (transaction, effects) = transfer?;
+                let output_objects: Vec<_> = objects
+                    .by_ref()
+                    .take(effects.all_changed_objects().len())
+                    .flatten()
+                    .collect();
+                let summary = NativeTransferSummary::new(
+                    transaction.transaction_data(),
+                    &effects,
+                    &output_objects,
+                );
+                if include_source_balances {
+                    let source = sources.next().flatten()?;
+                    summary?.with_source_balances(&[source])
+                } else {
+                    summary
+                }
+            })
+            .collect()
+    }
+
+    /// Returns every version of `coin_id` written by a native transaction, oldest first.
+    ///
+    /// The lineage is reconstructed by walking back from the latest version of the coin, following
+    /// each writing transaction's effects to the version it consumed. The walk ends at the
+    /// transaction that created the coin, or earlier if older versions have been pruned.
+    #[instrument(level = "trace", skip_all)]
+    pub fn get_native_transfer_lineage(
+        &self,
+        coin_id: ObjectID,
+    ) -> Vec<NativeTransferLineageEntry> {
+        let object_reader = self.get_object_cache_reader();
+        let transaction_reader = self.get_transaction_cache_reader();
+
+        let mut lineage = vec![];
+        let mut next_version = object_reader.get_object(&coin_id);
+        while let Some(coin) = next_version {
+            let digest = coin.previous_transaction;
+            let (Some(transaction), Some(effects)) = (
+                transaction_reader.get_transaction_block(&digest),
+                transaction_reader.get_executed_effects(&digest),
+            ) else {
+                break;
+            };
+
+            if matches!(
+                transaction.transaction_data().kind(),
+                TransactionKind::NativeTransfer(_)
+                    | TransactionKind::NativeTopUp(_)
+                    | TransactionKind::NativeMultiTransfer(_)
+                    | TransactionKind::NativeMerge(_)
+                    | TransactionKind::NativeTransferWhole(_)
+                    | TransactionKind::NativeTransferWithChange(_)
+                    | TransactionKind::NativeSweep(_)
+                    | TransactionKind::NativeTransferAfterEpoch(_)
+                    | TransactionKind::NativeSplitEqual(_)
+                    | TransactionKind::NativeTransferInto(_)
+                    | TransactionKind::NativeTransferWithConsent(_)
+            ) && let Ok(balance) = GasCoin::try_from(&coin)
+            {
+                lineage.push(NativeTransferLineageEntry {
+                    version: coin.version(),
+                    transaction_digest: digest,
+                    balance: balance.value(),
+                });
+            }
+
+            next_version = effects
+                .modified_at_versions()
+                .into_iter()
+                .find(|(id, _)| *id == coin_id)
+                .and_then(|(_, version)| object_reader.get_object_by_key(&coin_id, version));
+        }
+        lineage.reverse();
+        lineage
+    }
+
+    /// Returns a summary of every successful native transfer in checkpoint `sequence_number`, in
+    /// checkpoint order, keyed by its canonical sort key. Other transactions in the checkpoint are
+    /// skipped.
+    #[instrument(level = "trace", skip_all)]
+    pub fn get_native_transfers_in_checkpoint(
+        &self,
+        sequence_number: CheckpointSequenceNumber,
+    ) -> SuiResult<Vec<(NativeTransferSortKey, NativeTransferSummary)>> {
+        let contents = self.get_checkpoint_contents_by_sequence_number(sequence_number)?;
+        let digests = contents.iter().map(|digests| digests.transaction).collect();
+        Ok(self
+            .get_native_transfer_effects_batch(digests, false)
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, summary)| {
+                // Summaries cover single-recipient transfers, whose only output is at index 0.
+                let key = NativeTransferSortKey {
+                    checkpoint: sequence_number,
+                    transaction_index: index as u64,
+                    output_index: 0,
+                };
+                summary.map(|summary| (key, summary))
+            })
+            .collect())
+    }
+
+    /// Returns up to `limit` successful native transfers in executed checkpoints, ordered by sort
+    /// key and starting strictly after `cursor`, or from the lowest unpruned checkpoint if
+    /// `cursor` is `None`. Checkpoints that have been pruned are skipped.
+    ///
+    /// A call reads at most `MAX_NATIVE_TRANSFER_PAGE_CHECKPOINTS` checkpoints, so a page can hold
+    /// fewer than `limit` transfers even when more follow. Passing the page's `next_cursor` to the
+    /// next call neither repeats nor skips transfers.
+    #[instrument(level = "trace", skip_all)]
+    pub fn get_native_transfers_page(
+        &self,
+        cursor: Option<NativeTransferSortKey>,
+        limit: usize,
+    ) -> SuiResult<NativeTransferPage> {
+        let highest_executed = self.get_latest_checkpoint_sequence_number()?;
+        let lowest_available = self
+            .get_checkpoint_store()
+            .get_highest_pruned_checkpoint_seq_number()?
+            .map_or(0, |highest_pruned| highest_pruned + 1);
+        let start = cursor
+            .map_or(0, |cursor| cursor.checkpoint)
+            .max(lowest_available);
+        let end =
+            highest_executed.min(start.saturating_add(MAX_NATIVE_TRANSFER_PAGE_CHECKPOINTS - 1));
+
+        let mut transfers = vec![];
+        let mut next_cursor = cursor;
+        for sequence_number in start..=end {
+            for (key, summary) in self.get_native_transfers_in_checkpoint(sequence_number)? {
+                if cursor.is_some_and(|cursor| key <= cursor) {
+                    continue;
+                }
+                if transfers.len() == limit {
+                    return Ok(NativeTransferPage {
+                        transfers,
+                        next_cursor,
+                    });
+                }
+                next_cursor = Some(key);
+                transfers.push((key, summary));
+            }
+            next_cursor = Some(NativeTransferSortKey::end_of_checkpoint(sequence_number));
+        }
+        Ok(NativeTransferPage {
+            transfers,
+            next_cursor,
+        })
+    }
+
+    /// Returns the total MIST moved by successful native transfers since genesis. Transfers are
+    /// counted once their checkpoint is committed, and the total persists across restarts.
+    pub fn get_total_native_transfer_amount(&self) -> u128 {
+        self.get_transaction_cache_reader()
+            .get_total_native_transfer_amount()
+    }
+
     fn get_indexes(&self) -> SuiResult<Arc<IndexStore>> {
         match &self.indexes {
             Some(i) => Ok(i.clone()),
@@ -5137,6 +5633,21 @@ impl AuthorityState {
         epoch_store.get_signed_transaction(&lock_info.tx_digest)
     }
 
+    /// Returns the transaction that currently locks the given coin version, if any, so clients can
+    /// avoid building transfers that would fail the lock check. Reads the same lock table that
+    /// signing consults. Returns None if the version is unlocked, no longer live, or its lock
+    /// record cannot be read.
+    pub fn is_coin_locked(&self, object_ref: &ObjectRef) -> Option<TransactionDigest> {
+        let epoch_store = self.load_epoch_store_one_call_per_task();
+        match self
+            .get_object_cache_reader()
+            .get_lock(*object_ref, &epoch_store)
+        {
+            Ok(ObjectLockStatus::LockedToTx { locked_by_tx }) => Some(locked_by_tx.tx_digest),
+            _ => None,
+        }
+    }
+
     pub async fn get_objects(&self, objects: &[ObjectID]) -> Vec<Option<Object>> {
         self.get_object_cache_reader().get_objects(objects)
     }
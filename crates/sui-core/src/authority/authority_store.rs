@@ -119,6 +119,10 @@ pub struct AuthorityStore {
     /// Whether to enable expensive SUI conservation check at epoch boundaries.
     enable_epoch_sui_conservation_check: bool,
 
+    /// The committed value of `perpetual_tables.total_native_transfer_amount`. Each commit adds
+    /// to it while holding the lock, so commits never read a stale total.
+    total_native_transfer_amount: parking_lot::Mutex<u128>,
+
     metrics: AuthorityStoreMetrics,
 }
 
@@ -227,6 +231,7 @@ impl AuthorityStore {
         enable_epoch_sui_conservation_check: bool,
         registry: &Registry,
     ) -> SuiResult<Arc<Self>> {
+        let total_native_transfer_amount = perpetual_tables.get_total_native_transfer_amount()?;
         let store = Arc::new(Self {
             mutex_table: MutexTable::new(NUM_SHARDS),
             perpetual_tables,
@@ -235,6 +240,7 @@ impl AuthorityStore {
                 (CheckpointSequenceNumber, GlobalStateHash),
             >::new(),
             enable_epoch_sui_conservation_check,
+            total_native_transfer_amount: parking_lot::Mutex::new(total_native_transfer_amount),
             metrics: AuthorityStoreMetrics::new(registry),
         });
         // Only initialize an empty database.
@@ -283,6 +289,7 @@ impl AuthorityStore {
         enable_epoch_sui_conservation_check: bool,
         registry: &Registry,
     ) -> SuiResult<Arc<Self>> {
+        let total_native_transfer_amount = perpetual_tables.get_total_native_transfer_amount()?;
         let store = Arc::new(Self {
             mutex_table: MutexTable::new(NUM_SHARDS),
             perpetual_tables,
@@ -291,6 +298,7 @@ impl AuthorityStore {
                 (CheckpointSequenceNumber, GlobalStateHash),
             >::new(),
             enable_epoch_sui_conservation_check,
+            total_native_transfer_amount: parking_lot::Mutex::new(total_native_transfer_amount),
             metrics: AuthorityStoreMetrics::new(registry),
         });
         Ok(store)
@@ -856,6 +864,36 @@ impl AuthorityStore {
         Ok(())
     }
 
+    /// Writes a batch built by `build_db_batch`, adding the amounts of the successful native
+    /// transfers in `tx_outputs` to the running total in the same batch. The batch for a checkpoint
+    /// may be built before the previous checkpoint is committed, so the total is only read and
+    /// advanced here, under its lock.
+    pub fn write_db_batch(
+        &self,
+        mut write_batch: DBBatch,
+        tx_outputs: &[Arc<TransactionOutputs>],
+    ) -> SuiResult {
+        let amount: u128 = tx_outputs
+            .iter()
+            .filter(|outputs| outputs.effects.status().is_ok())
+            .map(|outputs| native_transfer_amount(outputs))
+            .sum();
+        if amount == 0 {
+            write_batch.write()?;
+            return Ok(());
+        }
+
+        let mut total = self.total_native_transfer_amount.lock();
+        let new_total = *total + amount;
+        write_batch.insert_batch(
+            &self.perpetual_tables.total_native_transfer_amount,
+            [((), new_total)],
+        )?;
+        write_batch.write()?;
+        *total = new_total;
+        Ok(())
+    }
+
     /// Commits transactions only (not effects or other transaction outputs) to the db.
     /// See ExecutionCache::persist_transaction for more info
     pub(crate) fn persist_transaction(&self, tx: &VerifiedExecutableTransaction) -> SuiResult {
@@ -1635,6 +1673,53 @@ impl AuthorityStore {
     }
 }
 
+/// The MIST a successful native transaction moves to addresses other than its sender. Top-ups and
+/// merges only move funds between the sender's own coins.
+fn native_transfer_amount(outputs: &TransactionOutputs) -> u128 {
+    let coin_value = |id: &ObjectID| {
+        outputs
+            .written
+            .get(id)
+            .map_or(0, |coin| coin.get_coin_value_unsafe() as u128)
+    };
+    match outputs.transaction.transaction_data().kind() {
+        TransactionKind::NativeTransfer(transfer)
+        | TransactionKind::NativeTransferWithConsent(transfer) => transfer.amount as u128,
+        TransactionKind::NativeTransferAfterEpoch(transfer) => transfer.amount as u128,
+        TransactionKind::NativeTransferInto(transfer) => transfer.amount as u128,
+        TransactionKind::NativeMultiTransfer(transfer) => transfer
+            .recipients
+            .iter()
+            .map(|(_, amount)| *amount as u128)
+            .sum(),
+        TransactionKind::NativeTransferWhole(transfer) => coin_value(&transfer.coin.0),
+        TransactionKind::NativeSweep(sweep) => {
+            sweep.coins.iter().map(|coin| coin_value(&coin.0)).sum()
+        }
+        // Both split the source coin into new coins for other addresses and delete it.
+        TransactionKind::NativeTransferWithChange(_) | TransactionKind::NativeSplitEqual(_) => {
+            outputs
+                .effects
+                .created()
+                .iter()
+                .map(|((id, _, _), _)| coin_value(id))
+                .sum()
+        }
+        TransactionKind::NativeTopUp(_) | TransactionKind::NativeMerge(_) => 0,
+        TransactionKind::ProgrammableTransaction(_)
+        | TransactionKind::ChangeEpoch(_)
+        | TransactionKind::Genesis(_)
+        | TransactionKind::ConsensusCommitPrologue(_)
+        | TransactionKind::AuthenticatorStateUpdate(_)
+        | TransactionKind::EndOfEpochTransaction(_)
+        | TransactionKind::RandomnessStateUpdate(_)
+        | TransactionKind::ConsensusCommitPrologueV2(_)
+        | TransactionKind::ConsensusCommitPrologueV3(_)
+        | TransactionKind::ConsensusCommitPrologueV4(_)
+        | TransactionKind::ProgrammableSystemTransaction(_) => 0,
+    }
+}
+
 impl GlobalStateHashStore for AuthorityStore {
     fn get_object_ref_prior_to_key_deprecated(
         &self,
@@ -134,6 +134,11 @@ pub struct AuthorityPerpetualTables {
     /// This number is the result of storage_fund_balance - sum(storage_rebate).
     pub(crate) expected_storage_fund_imbalance: DBMap<(), i64>,
 
+    /// Total MIST moved by successful native transfers committed to this store. Only ever grows,
+    /// and is updated in the same batch as the transfers it counts. It can exceed the total
+    /// supply, since the same MIST can be transferred many times.
+    pub(crate) total_native_transfer_amount: DBMap<(), u128>,
+
     /// Table that stores the set of received objects and deleted objects and the version at
     /// which they were received. This is used to prevent possible race conditions around receiving
     /// objects (since they are not locked by the transaction manager) and for tracking shared
@@ -384,6 +389,10 @@ impl AuthorityPerpetualTables {
                 "expected_storage_fund_imbalance".to_string(),
                 ThConfig::new(0, 1, KeyType::uniform(1)),
             ),
+            (
+                "total_native_transfer_amount".to_string(),
+                ThConfig::new(0, 1, KeyType::uniform(1)),
+            ),
             (
                 "object_per_epoch_marker_table".to_string(),
                 ThConfig::new_with_config_indexing(
@@ -585,6 +594,10 @@ impl AuthorityPerpetualTables {
         Ok(())
     }
 
+    pub fn get_total_native_transfer_amount(&self) -> Result<u128, TypedStoreError> {
+        Ok(self.total_native_transfer_amount.get(&())?.unwrap_or(0))
+    }
+
     pub fn get_highest_pruned_checkpoint(
         &self,
     ) -> Result<Option<CheckpointSequenceNumber>, TypedStoreError> {
@@ -812,6 +812,9 @@ impl ExecutionTimeEstimator {
     }
 
     pub fn get_estimate(&self, tx: &TransactionData) -> Duration {
+        if let Some(estimate) = native_transfer_estimate(tx.kind()) {
+            return estimate.min(Duration::from_micros(self.protocol_params.max_estimate_us));
+        }
         let TransactionKind::ProgrammableTransaction(tx) = tx.kind() else {
             debug_fatal!("get_estimate called on non-ProgrammableTransaction");
             return Duration::ZERO;
@@ -867,6 +870,43 @@ impl ExecutionTimeEstimator {
     }
 }
 
+// Native transfers run a fixed amount of work in the executor rather than Move code, so they
+// get a calibrated estimate instead of consensus observations.
+const NATIVE_TRANSFER_BASE_COST: Duration = Duration::from_micros(100);
+const NATIVE_TRANSFER_PER_OBJECT_COST: Duration = Duration::from_micros(50);
+
+/// Returns the estimated execution time of a native transfer kind, or `None` for any other kind.
+/// The estimate grows with the number of objects the transfer reads or writes, so a
+/// multi-recipient transfer or a merge scales with its length.
+pub fn native_transfer_estimate(kind: &TransactionKind) -> Option<Duration> {
+    let objects_touched = match kind {
+        // Source coin plus the newly created coin.
+        TransactionKind::NativeTransfer(_) => 2,
+        // Source and target coins.
+        TransactionKind::NativeTopUp(_) => 2,
+        // Source coin plus one created coin per recipient.
+        TransactionKind::NativeMultiTransfer(transfer) => 1 + transfer.recipients.len(),
+        // Primary coin plus every deleted coin.
+        TransactionKind::NativeMerge(merge) => 1 + merge.coins_to_merge.len(),
+        // The coin changes owner in place.
+        TransactionKind::NativeTransferWhole(_) => 1,
+        // Deleted source coin plus the recipient's and the change recipient's coins.
+        TransactionKind::NativeTransferWithChange(_) => 3,
+        // Every coin changes owner in place.
+        TransactionKind::NativeSweep(sweep) => sweep.coins.len(),
+        // Same as a plain transfer once its epoch arrives.
+        TransactionKind::NativeTransferAfterEpoch(_) => 2,
+        // Deleted source coin plus one created coin per part.
+        TransactionKind::NativeSplitEqual(split) => 1 + split.parts as usize,
+        // Source coin and the recipient's existing coin.
+        TransactionKind::NativeTransferInto(_) => 2,
+        // Same as a plain transfer; the recipient's signature is checked before execution.
+        TransactionKind::NativeTransferWithConsent(_) => 2,
+        _ => return None,
+    };
+    Some(NATIVE_TRANSFER_BASE_COST + NATIVE_TRANSFER_PER_OBJECT_COST * objects_touched as u32)
+}
+
 fn command_length(command: &Command) -> NonZeroUsize {
     // Commands with variable-length inputs/outputs are reported as +1
     // to account for fixed overhead and prevent divide-by-zero.
@@ -892,7 +932,7 @@ mod tests {
         MockConsensusClient,
     };
     use sui_protocol_config::ProtocolConfig;
-    use sui_types::base_types::{ObjectID, SequenceNumber, SuiAddress};
+    use sui_types::base_types::{ObjectID, SequenceNumber, SuiAddress, random_object_ref};
     use sui_types::transaction::{
         Argument, CallArg, ObjectArg, ProgrammableMoveCall, SharedObjectMutability,
     };
@@ -1866,6 +1906,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_native_transfer_estimate() {
+        let sender = SuiAddress::random_for_testing_only();
+        let recipient = SuiAddress::random_for_testing_only();
+        let coin = random_object_ref();
+
+        let transfer = TransactionData::new_native_transfer(sender, coin, recipient, 10);
+        let estimate = native_transfer_estimate(transfer.kind()).unwrap();
+        assert!(estimate > Duration::ZERO);
+        // The estimate is fixed, so repeated calls agree.
+        assert_eq!(native_transfer_estimate(transfer.kind()), Some(estimate));
+
+        // Each extra recipient adds one created coin to the estimate.
+        let multi_transfer = |n: usize| {
+            TransactionData::new_native_transfer_multi(sender, coin, vec![(recipient, 1); n])
+        };
+        let one = native_transfer_estimate(multi_transfer(1).kind()).unwrap();
+        let three = native_transfer_estimate(multi_transfer(3).kind()).unwrap();
+        assert_eq!(one, estimate);
+        assert_eq!(three - one, NATIVE_TRANSFER_PER_OBJECT_COST * 2);
+
+        let ptb = TransactionData::new_programmable(
+            sender,
+            vec![],
+            ProgrammableTransaction {
+                inputs: vec![],
+                commands: vec![],
+            },
+            100,
+            100,
+        );
+        assert_eq!(native_transfer_estimate(ptb.kind()), None);
+    }
+
     #[derive(Debug, Clone, Serialize, Deserialize)]
     struct ExecutionTimeObserverSnapshot {
         protocol_version: u64,
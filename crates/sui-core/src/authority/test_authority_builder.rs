@@ -40,7 +40,7 @@ use sui_network::randomness;
 use sui_protocol_config::{Chain, ProtocolConfig};
 use sui_swarm_config::genesis_config::AccountConfig;
 use sui_swarm_config::network_config::NetworkConfig;
-use sui_types::base_types::{AuthorityName, ObjectID};
+use sui_types::base_types::{AuthorityName, ObjectID, ObjectRef, SuiAddress};
 use sui_types::crypto::AuthorityKeyPair;
 use sui_types::digests::ChainIdentifier;
 use sui_types::executable_transaction::VerifiedExecutableTransaction;
@@ -60,6 +60,7 @@ pub struct TestAuthorityBuilder<'a> {
     node_keypair: Option<&'a AuthorityKeyPair>,
     genesis: Option<&'a Genesis>,
     starting_objects: Option<&'a [Object]>,
+    gas_coins: Vec<Object>,
     expensive_safety_checks: Option<ExpensiveSafetyCheckConfig>,
     disable_indexer: bool,
     accounts: Vec<AccountConfig>,
@@ -85,6 +86,21 @@ impl<'a> TestAuthorityBuilder<'a> {
         self
     }
 
+    /// Seeds one gas coin owned by `owner` for each of `amounts`, returning the coins' references
+    /// in the same order. Can be called several times, and alongside `with_starting_objects`.
+    pub fn with_gas_coins(mut self, owner: SuiAddress, amounts: &[u64]) -> (Self, Vec<ObjectRef>) {
+        let coins: Vec<_> = amounts
+            .iter()
+            .map(|amount| Object::with_id_owner_gas_for_testing(ObjectID::random(), owner, *amount))
+            .collect();
+        let refs = coins
+            .iter()
+            .map(|coin| coin.compute_object_reference())
+            .collect();
+        self.gas_coins.extend(coins);
+        (self, refs)
+    }
+
     pub fn with_store(mut self, store: Arc<AuthorityStore>) -> Self {
         assert!(self.store.replace(store).is_none());
         self
@@ -446,6 +462,12 @@ impl<'a> TestAuthorityBuilder<'a> {
                 .await
                 .unwrap();
         };
+        if !self.gas_coins.is_empty() {
+            state
+                .insert_objects_unsafe_for_testing_only(&self.gas_coins)
+                .await
+                .unwrap();
+        }
         state
     }
 }
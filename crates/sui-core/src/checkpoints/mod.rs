@@ -2009,8 +2009,18 @@ impl CheckpointBuilder {
                     TransactionKind::ProgrammableSystemTransaction(_) => {
                         // settlement transactions are added by checkpoint builder
                     }
-                    TransactionKind::NativeTransfer(_) => {
-                        // NativeTransfer transactions are handled normally
+                    TransactionKind::NativeTransfer(_)
+                    | TransactionKind::NativeTopUp(_)
+                    | TransactionKind::NativeMultiTransfer(_)
+                    | TransactionKind::NativeMerge(_)
+                    | TransactionKind::NativeTransferWhole(_)
+                    | TransactionKind::NativeTransferWithChange(_)
+                    | TransactionKind::NativeSweep(_)
+                    | TransactionKind::NativeTransferAfterEpoch(_)
+                    | TransactionKind::NativeSplitEqual(_)
+                    | TransactionKind::NativeTransferInto(_)
+                    | TransactionKind::NativeTransferWithConsent(_) => {
+                        // Native transactions are handled normally
                     }
                     TransactionKind::ChangeEpoch(_)
                     | TransactionKind::Genesis(_)
@@ -3825,6 +3835,10 @@ mod tests {
         ) -> Option<Vec<sui_types::storage::ObjectKey>> {
             unimplemented!()
         }
+
+        fn get_total_native_transfer_amount(&self) -> u128 {
+            unimplemented!()
+        }
     }
 
     #[async_trait::async_trait]
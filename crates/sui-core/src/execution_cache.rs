@@ -526,6 +526,9 @@ pub trait TransactionCacheRead: Send + Sync {
 
     fn take_accumulator_events(&self, digest: &TransactionDigest) -> Option<Vec<AccumulatorEvent>>;
 
+    /// Total MIST moved by successful native transfers that have been committed to the db.
+    fn get_total_native_transfer_amount(&self) -> u128;
+
     fn notify_read_executed_effects_digests<'a>(
         &'a self,
         task_name: &'static str,
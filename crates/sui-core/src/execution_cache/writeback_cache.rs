@@ -1055,7 +1055,9 @@ impl WritebackCache {
         // Flush writes to disk before removing anything from dirty set. otherwise,
         // a cache eviction could cause a value to disappear briefly, even if we insert to the
         // cache before removing from the dirty set.
-        db_batch.write().expect("db error");
+        self.store
+            .write_db_batch(db_batch, &all_outputs)
+            .expect("db error");
 
         let _metrics_guard =
             mysten_metrics::monitored_scope("WritebackCache::commit_transaction_outputs::flush");
@@ -2211,6 +2213,13 @@ impl TransactionCacheRead for WritebackCache {
             .get(digest)
             .map(|transaction_output| transaction_output.take_accumulator_events())
     }
+
+    fn get_total_native_transfer_amount(&self) -> u128 {
+        self.store
+            .perpetual_tables
+            .get_total_native_transfer_amount()
+            .expect("db error")
+    }
 }
 
 impl ExecutionCacheWrite for WritebackCache {
@@ -30,6 +30,7 @@ pub mod mock_checkpoint_builder;
 pub mod mock_consensus;
 pub mod module_cache_metrics;
 pub mod mysticeti_adapter;
+pub mod native_transfer_reconciler;
 pub mod overload_monitor;
 mod par_index_live_object_set;
 pub(crate) mod post_consensus_tx_reorder;
@@ -0,0 +1,160 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::authority::AuthorityState;
+use mysten_metrics::monitored_scope;
+use std::sync::Weak;
+use std::time::Duration;
+use sui_types::digests::TransactionDigest;
+use sui_types::error::SuiResult;
+use sui_types::messages_checkpoint::CheckpointSequenceNumber;
+use sui_types::native_transfer::NativeTransferSummary;
+use tokio::time::sleep;
+use tracing::{error, info, warn};
+
+// How often newly executed checkpoints are reconciled.
+const RECONCILE_INTERVAL: Duration = Duration::from_secs(60);
+
+// Periodically re-reads the native transfers in newly executed checkpoints from stored effects and
+// objects, and checks that each moved exactly as much out of its source coin as it created for the
+// recipient. Reconciliation starts from the checkpoint executed when the task starts.
+pub async fn native_transfer_reconciler(authority_state: Weak<AuthorityState>) {
+    info!("Starting native transfer reconciler.");
+
+    let mut next_checkpoint = None;
+    loop {
+        sleep(RECONCILE_INTERVAL).await;
+        let Some(authority) = authority_state.upgrade() else {
+            // `authority_state` doesn't exist anymore. Quit the reconciler.
+            break;
+        };
+        next_checkpoint = reconcile_new_checkpoints(&authority, next_checkpoint);
+    }
+
+    info!("Shut down native transfer reconciler.");
+}
+
+// Reconciles every executed checkpoint from `next_checkpoint` on, and returns the checkpoint to
+// resume from on the next round. Checkpoints pruned in the meantime are skipped.
+pub(crate) fn reconcile_new_checkpoints(
+    authority: &AuthorityState,
+    next_checkpoint: Option<CheckpointSequenceNumber>,
+) -> Option<CheckpointSequenceNumber> {
+    let _scope = monitored_scope("NativeTransferReconciler::reconcile_new_checkpoints");
+    let Ok(highest_executed) = authority.get_latest_checkpoint_sequence_number() else {
+        return next_checkpoint;
+    };
+    let Ok(highest_pruned) = authority
+        .get_checkpoint_store()
+        .get_highest_pruned_checkpoint_seq_number()
+    else {
+        return next_checkpoint;
+    };
+    let lowest_available = highest_pruned.map_or(0, |highest_pruned| highest_pruned + 1);
+
+    let start = next_checkpoint
+        .unwrap_or(highest_executed)
+        .max(lowest_available);
+    for sequence_number in start..=highest_executed {
+        // A checkpoint that cannot be read is skipped rather than retried, so that one missing
+        // checkpoint does not stall reconciliation of every later one.
+        if let Err(e) = reconcile_native_transfers_in_checkpoint(authority, sequence_number) {
+            warn!("Skipping native transfers in checkpoint {sequence_number}: {e}");
+        }
+    }
+    Some(highest_executed + 1)
+}
+
+/// Checks value conservation for every successful native transfer in checkpoint
+/// `sequence_number`, and returns the digests of those that violate it. Each violation is logged
+/// and counted in `native_transfer_conservation_violations`.
+///
+/// Only `NativeTransfer`s and `NativeTransferWithConsent`s are checked, since the check is built on
+/// `NativeTransferSummary`. Other native kinds in the checkpoint are skipped.
+pub fn reconcile_native_transfers_in_checkpoint(
+    authority: &AuthorityState,
+    sequence_number: CheckpointSequenceNumber,
+) -> SuiResult<Vec<TransactionDigest>> {
+    let contents = authority.get_checkpoint_contents_by_sequence_number(sequence_number)?;
+    let digests = contents.iter().map(|digests| digests.transaction).collect();
+    let summaries: Vec<_> = authority
+        .get_native_transfer_effects_batch(digests, true)
+        .into_iter()
+        .flatten()
+        .collect();
+
+    let violations = conservation_violations(&summaries);
+    for summary in &summaries {
+        if violations.contains(&summary.transaction_digest) {
+            error!(
+                tx_digest = ?summary.transaction_digest,
+                source_balances = ?summary.source_balances,
+                recipient_amount = summary.recipient_amount,
+                "Native transfer in checkpoint {sequence_number} did not conserve value"
+            );
+            authority
+                .metrics
+                .native_transfer_conservation_violations
+                .inc();
+        }
+    }
+    Ok(violations)
+}
+
+// Returns the transfers whose source coin lost a different amount than the recipient's coin holds.
+// Transfers whose source balances are no longer available cannot be checked and are skipped.
+fn conservation_violations(summaries: &[NativeTransferSummary]) -> Vec<TransactionDigest> {
+    summaries
+        .iter()
+        .filter(|summary| {
+            summary
+                .source_balances
+                .is_some_and(|balances| balances.delta != summary.recipient_amount)
+        })
+        .map(|summary| summary.transaction_digest)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sui_types::base_types::{ObjectID, SequenceNumber, SuiAddress};
+    use sui_types::gas_coin::GAS;
+    use sui_types::native_transfer::NativeTransferSourceBalances;
+
+    fn summary(delta: u64, recipient_amount: u64) -> NativeTransferSummary {
+        NativeTransferSummary {
+            transaction_digest: TransactionDigest::random(),
+            sender: SuiAddress::random_for_testing_only(),
+            recipient: SuiAddress::random_for_testing_only(),
+            source_coin: ObjectID::random(),
+            source_remaining: 1000,
+            created_coin: ObjectID::random(),
+            created_coin_version: SequenceNumber::from_u64(2),
+            recipient_amount,
+            coin_type: GAS::type_tag(),
+            source_balances: Some(NativeTransferSourceBalances {
+                pre_balance: 1000 + delta,
+                post_balance: 1000,
+                delta,
+            }),
+        }
+    }
+
+    #[test]
+    fn test_conservation_violations() {
+        let conserved = summary(300, 300);
+        let minted = summary(300, 301);
+        let burned = summary(300, 299);
+        let unchecked = NativeTransferSummary {
+            source_balances: None,
+            ..summary(300, 1)
+        };
+
+        assert!(conservation_violations(&[conserved.clone(), unchecked.clone()]).is_empty());
+        assert_eq!(
+            conservation_violations(&[conserved, minted.clone(), unchecked, burned.clone()]),
+            vec![minted.transaction_digest, burned.transaction_digest]
+        );
+    }
+}
@@ -259,6 +259,19 @@ pub fn overload_monitor_accept_tx(
     Ok(())
 }
 
+// Checks if `now_ms` is within `window` of the expected end of the epoch, or past it. An epoch
+// that runs over its expected duration stays in the window until reconfiguration.
+pub fn is_within_epoch_end_window(
+    now_ms: u64,
+    epoch_start_timestamp_ms: u64,
+    epoch_duration_ms: u64,
+    window: Duration,
+) -> bool {
+    let expected_end_ms = epoch_start_timestamp_ms.saturating_add(epoch_duration_ms);
+    let window_ms = u64::try_from(window.as_millis()).unwrap_or(u64::MAX);
+    now_ms.saturating_add(window_ms) >= expected_end_ms
+}
+
 #[cfg(test)]
 #[allow(clippy::disallowed_methods)] // allow unbounded_channel() since tests are simulating txn manager execution driver interaction.
 mod tests {
@@ -335,6 +348,39 @@ mod tests {
         assert_eq!(calculate_load_shedding_percentage(0.0, 1.0), 0);
     }
 
+    #[test]
+    pub fn test_is_within_epoch_end_window() {
+        let window = Duration::from_secs(30);
+        let epoch_start_ms = 1_000_000;
+        let epoch_duration_ms = 3_600_000;
+        let epoch_end_ms = epoch_start_ms + epoch_duration_ms;
+        let in_window = |now_ms, epoch_start_ms| {
+            is_within_epoch_end_window(now_ms, epoch_start_ms, epoch_duration_ms, window)
+        };
+
+        assert!(!in_window(epoch_start_ms, epoch_start_ms));
+        assert!(!in_window(epoch_end_ms - 30_001, epoch_start_ms));
+        assert!(in_window(epoch_end_ms - 30_000, epoch_start_ms));
+        assert!(in_window(epoch_end_ms - 1, epoch_start_ms));
+        // Still refused while the epoch runs over its expected end.
+        assert!(in_window(epoch_end_ms + 5_000, epoch_start_ms));
+        // Accepted again once the next epoch starts.
+        assert!(!in_window(epoch_end_ms + 5_000, epoch_end_ms + 5_000));
+
+        assert!(!is_within_epoch_end_window(
+            0,
+            epoch_start_ms,
+            epoch_duration_ms,
+            Duration::ZERO
+        ));
+        assert!(is_within_epoch_end_window(
+            0,
+            epoch_start_ms,
+            epoch_duration_ms,
+            Duration::MAX
+        ));
+    }
+
     #[test]
     pub fn test_check_overload_signals() {
         let config = AuthorityOverloadConfig {
@@ -13,6 +13,7 @@ use mysten_metrics::monitored_scope;
 use nonempty::NonEmpty;
 use parking_lot::{Mutex, MutexGuard, RwLock};
 use prometheus::{IntCounter, Registry, register_int_counter_with_registry};
+use rayon::prelude::*;
 use shared_crypto::intent::Intent;
 use std::sync::Arc;
 use sui_types::address_alias;
@@ -494,6 +495,29 @@ impl SignatureVerifier {
         )
     }
 
+    /// Verifies the user signatures of a burst of native transfers in parallel, returning one
+    /// result per transaction in input order. Any other transaction kind is rejected, since it
+    /// must go through `verify_tx_with_current_aliases` on the regular admission path.
+    ///
+    /// Unlike `multi_verify_certs`, this does not batch signatures: each transaction is verified
+    /// on its own, and only the work is spread across threads. Certificates all carry the
+    /// committee's BLS signatures, but user signatures may use any scheme, including multisig and
+    /// zkLogin.
+    pub fn par_verify_native_transfers(&self, txs: &[&SenderSignedData]) -> Vec<SuiResult> {
+        let _scope = monitored_scope("ParVerifyNativeTransfers");
+        txs.par_iter()
+            .map(|signed_tx| {
+                if !signed_tx.intent_message().value.kind().is_unmetered() {
+                    return Err(SuiErrorKind::UnsupportedFeatureError {
+                        error: "Only native transfers can be verified in parallel".to_string(),
+                    }
+                    .into());
+                }
+                self.verify_tx_require_no_aliases(signed_tx)
+            })
+            .collect()
+    }
+
     pub fn clear_signature_cache(&self) {
         self.certificate_cache.clear();
         self.signed_data_cache.clear();
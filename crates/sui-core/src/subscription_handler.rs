@@ -12,11 +12,13 @@ use tracing::{error, instrument, trace};
 
 use crate::streamer::Streamer;
 use sui_json_rpc_types::{
-    EffectsWithInput, EventFilter, SuiTransactionBlockEffects, SuiTransactionBlockEvents,
+    EffectsWithInput, EventFilter, Filter, SuiTransactionBlockEffects, SuiTransactionBlockEvents,
     TransactionFilter,
 };
 use sui_json_rpc_types::{SuiEvent, SuiTransactionBlockEffectsAPI};
+use sui_types::base_types::SuiAddress;
 use sui_types::error::SuiResult;
+use sui_types::native_transfer::NativeTransferSummary;
 use sui_types::transaction::TransactionData;
 
 #[cfg(test)]
@@ -67,9 +69,27 @@ impl SubscriptionMetrics {
     }
 }
 
+/// Selects which native transfers a subscriber receives.
+#[derive(Clone, Debug)]
+pub enum NativeTransferFilter {
+    Sender(SuiAddress),
+    Recipient(SuiAddress),
+}
+
+impl Filter<NativeTransferSummary> for NativeTransferFilter {
+    fn matches(&self, item: &NativeTransferSummary) -> bool {
+        match self {
+            NativeTransferFilter::Sender(sender) => item.sender == *sender,
+            NativeTransferFilter::Recipient(recipient) => item.recipient == *recipient,
+        }
+    }
+}
+
 pub struct SubscriptionHandler {
     event_streamer: Streamer<SuiEvent, SuiEvent, EventFilter>,
     transaction_streamer: Streamer<EffectsWithInput, SuiTransactionBlockEffects, TransactionFilter>,
+    native_transfer_streamer:
+        Streamer<NativeTransferSummary, NativeTransferSummary, NativeTransferFilter>,
 }
 
 impl SubscriptionHandler {
@@ -77,7 +97,16 @@ impl SubscriptionHandler {
         let metrics = Arc::new(SubscriptionMetrics::new(registry));
         Self {
             event_streamer: Streamer::spawn(EVENT_DISPATCH_BUFFER_SIZE, metrics.clone(), "event"),
-            transaction_streamer: Streamer::spawn(EVENT_DISPATCH_BUFFER_SIZE, metrics, "tx"),
+            transaction_streamer: Streamer::spawn(
+                EVENT_DISPATCH_BUFFER_SIZE,
+                metrics.clone(),
+                "tx",
+            ),
+            native_transfer_streamer: Streamer::spawn(
+                EVENT_DISPATCH_BUFFER_SIZE,
+                metrics,
+                "native_transfer",
+            ),
         }
     }
 }
@@ -112,6 +141,13 @@ impl SubscriptionHandler {
         Ok(())
     }
 
+    /// Streams the summary of a successful native transfer to its subscribers.
+    pub fn process_native_transfer(&self, summary: NativeTransferSummary) {
+        if let Err(e) = self.native_transfer_streamer.try_send(summary) {
+            error!(error =? e, "Failed to send native transfer to dispatch");
+        }
+    }
+
     pub fn subscribe_events(&self, filter: EventFilter) -> impl Stream<Item = SuiEvent> + use<> {
         self.event_streamer.subscribe(filter)
     }
@@ -122,4 +158,13 @@ impl SubscriptionHandler {
     ) -> impl Stream<Item = SuiTransactionBlockEffects> + use<> {
         self.transaction_streamer.subscribe(filter)
     }
+
+    /// Streams the summary of every successful `NativeTransfer` or `NativeTransferWithConsent`
+    /// matching `filter`. Other native kinds have no `NativeTransferSummary` and are not streamed.
+    pub fn subscribe_native_transfers(
+        &self,
+        filter: NativeTransferFilter,
+    ) -> impl Stream<Item = NativeTransferSummary> + use<> {
+        self.native_transfer_streamer.subscribe(filter)
+    }
 }
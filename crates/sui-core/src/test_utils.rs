@@ -9,6 +9,7 @@ use std::sync::Arc;
 use std::time::Duration;
 use sui_config::genesis::Genesis;
 use sui_macros::nondeterministic;
+use sui_protocol_config::{Chain, ProtocolConfig, ProtocolVersion};
 use sui_types::base_types::{FullObjectRef, ObjectID, random_object_ref};
 use sui_types::crypto::AuthorityKeyPair;
 use sui_types::crypto::{AccountKeyPair, AuthorityPublicKeyBytes, Signer};
@@ -18,6 +19,7 @@ use sui_types::signature_verification::VerifiedDigestCache;
 use sui_types::transaction::ObjectArg;
 use sui_types::transaction::{
     CallArg, SignedTransaction, TEST_ONLY_GAS_UNIT_FOR_TRANSFER, Transaction, TransactionData,
+    VerifiedTransaction,
 };
 use sui_types::utils::create_fake_transaction;
 use sui_types::utils::to_sender_signed_transaction;
@@ -287,6 +289,57 @@ pub fn make_dummy_tx(
     )
 }
 
+pub fn make_dummy_native_transfer_tx(
+    receiver: SuiAddress,
+    sender: SuiAddress,
+    sender_sec: &AccountKeyPair,
+) -> Transaction {
+    Transaction::from_data_and_signer(
+        TransactionData::new_native_transfer(sender, random_object_ref(), receiver, 1),
+        vec![sender_sec],
+    )
+}
+
+/// Make a native transfer of `amount` from `coin_ref` to `recipient`, signed by `sender_key`.
+/// The sender is the address of `sender_key`, which must own the coin.
+pub fn make_native_transfer_tx(
+    sender_key: &AccountKeyPair,
+    coin_ref: ObjectRef,
+    recipient: SuiAddress,
+    amount: u64,
+) -> VerifiedTransaction {
+    let sender = SuiAddress::from(&sender_key.public());
+    VerifiedTransaction::new_unchecked(to_sender_signed_transaction(
+        TransactionData::new_native_transfer(sender, coin_ref, recipient, amount),
+        sender_key,
+    ))
+}
+
+/// Get a protocol config with execution_version 2 (v2 execution engine)
+/// Protocol version 31 has execution_version 2, but we need to override the congestion control mode
+pub fn protocol_config_v2() -> ProtocolConfig {
+    use sui_protocol_config::{ExecutionTimeEstimateParams, PerObjectCongestionControlMode};
+    let mut config = ProtocolConfig::get_for_version(ProtocolVersion::new(31), Chain::Unknown);
+    // Set congestion control mode to ExecutionTimeEstimate (required for v2 execution engine)
+    config.set_per_object_congestion_control_mode_for_testing(
+        PerObjectCongestionControlMode::ExecutionTimeEstimate(ExecutionTimeEstimateParams {
+            target_utilization: 50,
+            allowed_txn_cost_overage_burst_limit_us: 500_000,
+            randomness_scalar: 20,
+            max_estimate_us: 1_500_000,
+            stored_observations_num_included_checkpoints: 10,
+            stored_observations_limit: 180,
+            stake_weighted_median_threshold: 3334,
+            default_none_duration_for_new_keys: true,
+            observations_chunk_size: Some(18),
+        }),
+    );
+    config.set_native_transfer_enabled_for_testing(true);
+    config.set_native_transfer_not_a_coin_status_for_testing(true);
+    config.set_native_insufficient_balance_status_for_testing(true);
+    config
+}
+
 /// Make a cert using an arbitrarily large committee.
 pub fn make_cert_with_large_committee(
     committee: &Committee,
@@ -2,7 +2,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::signature_verifier::*;
-use crate::test_utils::{make_cert_with_large_committee, make_dummy_tx};
+use crate::test_utils::{
+    make_cert_with_large_committee, make_dummy_native_transfer_tx, make_dummy_tx,
+};
 use fastcrypto::traits::KeyPair;
 use futures::future::join_all;
 use itertools::Itertools as _;
@@ -181,3 +183,54 @@ async fn test_async_verifier() {
 
     join_all(tasks).await;
 }
+
+#[tokio::test]
+async fn test_par_verify_native_transfers() {
+    use fastcrypto_zkp::bn254::zk_login_api::ZkLoginEnv;
+
+    let (committee, _) = Committee::new_simple_test_committee();
+    let verifier = SignatureVerifier::new(
+        Arc::new(committee),
+        Arc::new(InMemoryStorage::new(vec![])),
+        SignatureVerifierMetrics::new(&Registry::new()),
+        vec![],
+        ZkLoginEnv::Test,
+        true,
+        true,
+        true,
+        Some(30),
+        true,
+        true,
+    );
+
+    let (receiver, _): (_, AccountKeyPair) = get_key_pair();
+    let mut txs: Vec<_> = (0..16)
+        .map(|_| {
+            let (sender, sender_sec): (_, AccountKeyPair) = get_key_pair();
+            make_dummy_native_transfer_tx(receiver, sender, &sender_sec)
+        })
+        .collect();
+
+    // Swap in signatures over other transactions for every third transfer.
+    let invalid: Vec<usize> = (0..txs.len()).step_by(3).collect();
+    for &i in &invalid {
+        let (other_sender, other_sender_sec): (_, AccountKeyPair) = get_key_pair();
+        let other_tx = make_dummy_native_transfer_tx(receiver, other_sender, &other_sender_sec);
+        *txs[i]
+            .data_mut_for_testing()
+            .tx_signatures_mut_for_testing() = other_tx.data().tx_signatures().to_vec();
+    }
+
+    // Regular transactions are not accepted by the native transfer path.
+    let (sender, sender_sec): (_, AccountKeyPair) = get_key_pair();
+    txs.push(make_dummy_tx(receiver, sender, &sender_sec));
+
+    let results =
+        verifier.par_verify_native_transfers(&txs.iter().map(|tx| tx.data()).collect_vec());
+    assert_eq!(results.len(), txs.len());
+    let (last, transfers) = results.split_last().unwrap();
+    for (i, result) in transfers.iter().enumerate() {
+        assert_eq!(result.is_err(), invalid.contains(&i), "transfer {i}");
+    }
+    last.as_ref().unwrap_err();
+}
@@ -1,43 +1,61 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
-use sui_protocol_config::{Chain, ProtocolConfig, ProtocolVersion};
-use sui_types::base_types::{ObjectID, SuiAddress};
-use sui_types::crypto::get_account_key_pair;
-use sui_types::effects::TransactionEffectsAPI;
+use fastcrypto::hash::MultisetHash;
+use fastcrypto::traits::KeyPair;
+use futures::StreamExt;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use std::collections::BTreeSet;
+use std::sync::Arc;
+use std::time::Duration;
+use sui_config::node::AuthorityOverloadConfig;
+use sui_protocol_config::ProtocolConfig;
+use sui_types::SUI_FRAMEWORK_PACKAGE_ID;
+use sui_types::balance::Supply;
+use sui_types::base_types::{
+    ObjectDigest, ObjectID, ObjectRef, SuiAddress, TransactionDigest, random_object_ref,
+};
+use sui_types::coin::TreasuryCap;
+use sui_types::crypto::{Signature, Signer, get_account_key_pair};
+use sui_types::digests::{ChainIdentifier, CheckpointDigest};
+use sui_types::effects::{SignedTransactionEffects, TransactionEffects, TransactionEffectsAPI};
+use sui_types::error::{SuiErrorKind, SuiResult, UserInputError};
 use sui_types::executable_transaction::VerifiedExecutableTransaction;
 use sui_types::execution_status::{ExecutionFailureStatus, ExecutionStatus};
-use sui_types::gas_coin::GasCoin;
-use sui_types::object::Object;
-use sui_types::transaction::{TransactionData, TransactionDataAPI, VerifiedTransaction};
-use sui_types::utils::to_sender_signed_transaction;
+use sui_types::gas::GasCostSummary;
+use sui_types::gas_coin::{GAS, GasCoin};
+use sui_types::id::UID;
+use sui_types::messages_checkpoint::{
+    CertifiedCheckpointSummary, CheckpointContents, CheckpointSequenceNumber, CheckpointSummary,
+    SignedCheckpointSummary, VerifiedCheckpoint,
+};
+use sui_types::messages_grpc::TransactionStatus;
+use sui_types::native_transfer::{
+    NativeTransferLineageEntry, NativeTransferSortKey, NativeTransferSummary,
+};
+use sui_types::object::{MoveObject, Object};
+use sui_types::transaction::{
+    Transaction, TransactionData, TransactionDataAPI, TransactionExpiration, TransactionKind,
+    VerifiedTransaction,
+};
+use sui_types::utils::{
+    make_committee_key_num, to_sender_signed_transaction,
+    to_sender_signed_transaction_with_multi_signers,
+};
 
-use crate::authority::ExecutionEnv;
 use crate::authority::test_authority_builder::TestAuthorityBuilder;
+use crate::authority::{AuthorityState, ExecutionEnv};
+use crate::consensus_adapter::NoopConsensusOverloadChecker;
 use crate::execution_scheduler::SchedulingSource;
-use crate::test_utils::send_and_confirm_transaction;
-
-/// Get a protocol config with execution_version 2 (v2 execution engine)
-/// Protocol version 31 has execution_version 2, but we need to override the congestion control mode
-fn protocol_config_v2() -> ProtocolConfig {
-    use sui_protocol_config::{ExecutionTimeEstimateParams, PerObjectCongestionControlMode};
-    let mut config = ProtocolConfig::get_for_version(ProtocolVersion::new(31), Chain::Unknown);
-    // Set congestion control mode to ExecutionTimeEstimate (required for v2 execution engine)
-    config.set_per_object_congestion_control_mode_for_testing(
-        PerObjectCongestionControlMode::ExecutionTimeEstimate(ExecutionTimeEstimateParams {
-            target_utilization: 50,
-            allowed_txn_cost_overage_burst_limit_us: 500_000,
-            randomness_scalar: 20,
-            max_estimate_us: 1_500_000,
-            stored_observations_num_included_checkpoints: 10,
-            stored_observations_limit: 180,
-            stake_weighted_median_threshold: 3334,
-            default_none_duration_for_new_keys: true,
-            observations_chunk_size: Some(18),
-        }),
-    );
-    config
-}
+use crate::global_state_hasher::GlobalStateHasher;
+use crate::native_transfer_reconciler::{
+    reconcile_native_transfers_in_checkpoint, reconcile_new_checkpoints,
+};
+use crate::subscription_handler::NativeTransferFilter;
+use crate::test_utils::{
+    make_dummy_tx, make_native_transfer_tx, protocol_config_v2, send_and_confirm_transaction,
+};
 
 #[tokio::test]
 async fn test_native_transfer_success() {
@@ -51,7 +69,7 @@ async fn test_native_transfer_success() {
     let coin_object = Object::new_move(
         gas_coin.to_object(sui_types::base_types::SequenceNumber::from_u64(1)),
         sui_types::object::Owner::AddressOwner(sender),
-        sui_types::base_types::TransactionDigest::ZERO,
+        TransactionDigest::ZERO,
     );
     let coin_ref = coin_object.compute_object_reference();
 
@@ -65,6 +83,7 @@ async fn test_native_transfer_success() {
     let transfer_amount = 500;
     let tx_data =
         TransactionData::new_native_transfer(sender, coin_ref, recipient, transfer_amount);
+    let expected_coin_id = tx_data.native_transfer_created_coin_id().unwrap();
     let signed_tx = to_sender_signed_transaction(tx_data, &sender_key);
 
     // Execute the transaction
@@ -102,6 +121,10 @@ async fn test_native_transfer_success() {
     assert!(effects.deleted().is_empty(), "No objects should be deleted");
     let created_objects = effects.created();
     assert_eq!(created_objects.len(), 1, "Should create one new coin");
+    assert!(
+        effects.events_digest().is_none(),
+        "Native transfers should not emit events"
+    );
 
     // Verify the coin was updated correctly
     let updated_coin = state.get_object(&coin_id).await.unwrap();
@@ -114,6 +137,10 @@ async fn test_native_transfer_success() {
 
     // Verify new coin was created for recipient
     let new_coin_id = created_objects[0].0.0;
+    assert_eq!(
+        new_coin_id, expected_coin_id,
+        "New coin ID should match the one derived before execution"
+    );
     let new_coin = state.get_object(&new_coin_id).await.unwrap();
     let new_gas_coin = GasCoin::try_from(&new_coin).unwrap();
     assert_eq!(
@@ -140,7 +167,7 @@ async fn test_native_transfer_insufficient_balance() {
     let coin_object = Object::new_move(
         gas_coin.to_object(sui_types::base_types::SequenceNumber::from_u64(1)),
         sui_types::object::Owner::AddressOwner(sender),
-        sui_types::base_types::TransactionDigest::ZERO,
+        TransactionDigest::ZERO,
     );
     let coin_ref = coin_object.compute_object_reference();
 
@@ -167,14 +194,18 @@ async fn test_native_transfer_insufficient_balance() {
         );
         match effects.status() {
             ExecutionStatus::Failure {
-                error: ExecutionFailureStatus::InsufficientCoinBalance,
+                error:
+                    ExecutionFailureStatus::InsufficientCoinBalanceForTransfer {
+                        available: 100,
+                        requested: 500,
+                    },
                 ..
             } => {
                 // Expected failure
             }
             status => {
                 panic!(
-                    "Should fail with InsufficientCoinBalance, got: {:?}",
+                    "Should fail with InsufficientCoinBalanceForTransfer, got: {:?}",
                     status
                 );
             }
@@ -187,6 +218,43 @@ async fn test_native_transfer_insufficient_balance() {
     }
 }
 
+#[tokio::test]
+async fn test_native_transfer_insufficient_balance_without_status_flag() {
+    let (sender, sender_key) = get_account_key_pair();
+    let recipient = SuiAddress::random_for_testing_only();
+
+    let coin_object = Object::new_move(
+        GasCoin::new(ObjectID::random(), 100)
+            .to_object(sui_types::base_types::SequenceNumber::from_u64(1)),
+        sui_types::object::Owner::AddressOwner(sender),
+        TransactionDigest::ZERO,
+    );
+    let coin_ref = coin_object.compute_object_reference();
+
+    // Without the flag, an underfunded coin keeps failing with the status it always had.
+    let mut protocol_config = protocol_config_v2();
+    protocol_config.set_native_insufficient_balance_status_for_testing(false);
+    let state = TestAuthorityBuilder::new()
+        .with_protocol_config(protocol_config)
+        .with_starting_objects(&[coin_object])
+        .build()
+        .await;
+
+    let tx_data = TransactionData::new_native_transfer(sender, coin_ref, recipient, 500);
+    let signed_tx = to_sender_signed_transaction(tx_data, &sender_key);
+    let (_cert, effects) = send_and_confirm_transaction(&state, None, signed_tx)
+        .await
+        .unwrap();
+    assert_eq!(
+        effects.status(),
+        &ExecutionStatus::Failure {
+            error: ExecutionFailureStatus::InsufficientCoinBalance,
+            command: None,
+        }
+    );
+    assert!(effects.created().is_empty());
+}
+
 #[tokio::test]
 async fn test_native_transfer_wrong_owner() {
     let (sender, sender_key) = get_account_key_pair();
@@ -200,7 +268,7 @@ async fn test_native_transfer_wrong_owner() {
     let coin_object = Object::new_move(
         gas_coin.to_object(sui_types::base_types::SequenceNumber::from_u64(1)),
         sui_types::object::Owner::AddressOwner(other_owner), // Not owned by sender
-        sui_types::base_types::TransactionDigest::ZERO,
+        TransactionDigest::ZERO,
     );
     let coin_ref = coin_object.compute_object_reference();
 
@@ -244,7 +312,7 @@ async fn test_native_transfer_zero_amount() {
     let coin_object = Object::new_move(
         gas_coin.to_object(sui_types::base_types::SequenceNumber::from_u64(1)),
         sui_types::object::Owner::AddressOwner(sender),
-        sui_types::base_types::TransactionDigest::ZERO,
+        TransactionDigest::ZERO,
     );
     let coin_ref = coin_object.compute_object_reference();
 
@@ -268,6 +336,46 @@ async fn test_native_transfer_zero_amount() {
     );
 }
 
+#[tokio::test]
+async fn test_native_transfer_to_self() {
+    let (sender, sender_key) = get_account_key_pair();
+
+    let coin_object = Object::new_move(
+        GasCoin::new(ObjectID::random(), 1000)
+            .to_object(sui_types::base_types::SequenceNumber::from_u64(1)),
+        sui_types::object::Owner::AddressOwner(sender),
+        TransactionDigest::ZERO,
+    );
+    let coin_ref = coin_object.compute_object_reference();
+
+    let state = TestAuthorityBuilder::new()
+        .with_protocol_config(protocol_config_v2())
+        .with_starting_objects(&[coin_object])
+        .build()
+        .await;
+
+    let tx_data = TransactionData::new_native_transfer(sender, coin_ref, sender, 500);
+    let epoch_store = state.epoch_store_for_testing();
+    assert_eq!(
+        tx_data.validity_check(epoch_store.protocol_config()),
+        Err(UserInputError::NativeTransferToSelf { recipient: sender })
+    );
+
+    // Nothing is signed or executed.
+    let signed_tx = to_sender_signed_transaction(tx_data, &sender_key);
+    let err = send_and_confirm_transaction(&state, None, signed_tx)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.into_inner(),
+        SuiErrorKind::UserInputError {
+            error: UserInputError::NativeTransferToSelf { recipient: sender }
+        }
+    );
+    let coin = state.get_object(&coin_ref.0).await.unwrap();
+    assert_eq!(coin.compute_object_reference(), coin_ref);
+}
+
 #[tokio::test]
 async fn test_native_transfer_fast_path() {
     let (sender, sender_key) = get_account_key_pair();
@@ -280,7 +388,7 @@ async fn test_native_transfer_fast_path() {
     let coin_object = Object::new_move(
         gas_coin.to_object(sui_types::base_types::SequenceNumber::from_u64(1)),
         sui_types::object::Owner::AddressOwner(sender),
-        sui_types::base_types::TransactionDigest::ZERO,
+        TransactionDigest::ZERO,
     );
     let coin_ref = coin_object.compute_object_reference();
 
@@ -356,7 +464,7 @@ async fn test_native_transfer_full_amount() {
     let coin_object = Object::new_move(
         gas_coin.to_object(sui_types::base_types::SequenceNumber::from_u64(1)),
         sui_types::object::Owner::AddressOwner(sender),
-        sui_types::base_types::TransactionDigest::ZERO,
+        TransactionDigest::ZERO,
     );
     let coin_ref = coin_object.compute_object_reference();
 
@@ -423,6 +531,294 @@ async fn test_native_transfer_full_amount() {
     );
 }
 
+#[tokio::test]
+async fn test_native_transfer_whole() {
+    let (sender, sender_key) = get_account_key_pair();
+    let recipient = SuiAddress::random_for_testing_only();
+
+    let coin_id = ObjectID::random();
+    let coin_object = Object::new_move(
+        GasCoin::new(coin_id, 1000).to_object(sui_types::base_types::SequenceNumber::from_u64(1)),
+        sui_types::object::Owner::AddressOwner(sender),
+        TransactionDigest::ZERO,
+    );
+    let coin_ref = coin_object.compute_object_reference();
+
+    let state = TestAuthorityBuilder::new()
+        .with_protocol_config(protocol_config_v2())
+        .with_starting_objects(&[coin_object])
+        .build()
+        .await;
+
+    let tx_data = TransactionData::new_native_transfer_whole(sender, coin_ref, recipient);
+    let signed_tx = to_sender_signed_transaction(tx_data, &sender_key);
+    let (_cert, effects) = send_and_confirm_transaction(&state, None, signed_tx)
+        .await
+        .unwrap();
+    assert!(effects.status().is_ok(), "{:?}", effects.status());
+
+    // The coin changes hands in place: nothing is created or deleted.
+    assert!(effects.created().is_empty());
+    assert!(effects.deleted().is_empty());
+    assert_eq!(effects.mutated().len(), 1);
+    assert_eq!(effects.mutated()[0].0.0, coin_id);
+    assert_eq!(
+        effects.mutated()[0].1.get_address_owner_address().unwrap(),
+        recipient
+    );
+
+    let coin = state.get_object(&coin_id).await.unwrap();
+    assert_eq!(
+        coin.owner,
+        sui_types::object::Owner::AddressOwner(recipient)
+    );
+    assert_eq!(GasCoin::try_from(&coin).unwrap().value(), 1000);
+}
+
+#[tokio::test]
+async fn test_native_sweep() {
+    let (sender, sender_key) = get_account_key_pair();
+    let (other_owner, _) = get_account_key_pair();
+    let recipient = SuiAddress::random_for_testing_only();
+
+    let (builder, coin_refs) = TestAuthorityBuilder::new()
+        .with_protocol_config(protocol_config_v2())
+        .with_gas_coins(sender, &[100, 200, 300]);
+    let (builder, other_coin_refs) = builder.with_gas_coins(other_owner, &[400]);
+    let state = builder.build().await;
+
+    // A coin the sender does not own is rejected before anything moves.
+    let mut with_other_coin = coin_refs.clone();
+    with_other_coin.push(other_coin_refs[0]);
+    let tx_data = TransactionData::new_native_sweep(sender, with_other_coin, recipient);
+    let result = send_and_confirm_transaction(
+        &state,
+        None,
+        to_sender_signed_transaction(tx_data, &sender_key),
+    )
+    .await;
+    assert!(result.is_err(), "Should fail with wrong owner");
+
+    let tx_data = TransactionData::new_native_sweep(sender, coin_refs.clone(), recipient);
+    let (_cert, effects) = send_and_confirm_transaction(
+        &state,
+        None,
+        to_sender_signed_transaction(tx_data, &sender_key),
+    )
+    .await
+    .unwrap();
+    assert!(effects.status().is_ok(), "{:?}", effects.status());
+    assert_eq!(effects.gas_cost_summary().net_gas_usage(), 0);
+
+    // Every coin changes hands in place: nothing is created or deleted.
+    assert!(effects.created().is_empty());
+    assert!(effects.deleted().is_empty());
+    assert_eq!(effects.mutated().len(), coin_refs.len());
+    for (coin_ref, value) in coin_refs.iter().zip([100, 200, 300]) {
+        let coin = state.get_object(&coin_ref.0).await.unwrap();
+        assert_eq!(
+            coin.owner,
+            sui_types::object::Owner::AddressOwner(recipient)
+        );
+        assert_eq!(GasCoin::try_from(&coin).unwrap().value(), value);
+    }
+}
+
+#[tokio::test]
+async fn test_native_split_equal() {
+    let (sender, sender_key) = get_account_key_pair();
+    let recipient = SuiAddress::random_for_testing_only();
+    let (builder, coin_refs) = TestAuthorityBuilder::new()
+        .with_protocol_config(protocol_config_v2())
+        .with_gas_coins(sender, &[1000, 2]);
+    let state = builder.build().await;
+
+    let tx_data = TransactionData::new_native_split_equal(sender, coin_refs[0], 3, recipient);
+    let (_cert, effects) = send_and_confirm_transaction(
+        &state,
+        None,
+        to_sender_signed_transaction(tx_data, &sender_key),
+    )
+    .await
+    .unwrap();
+    assert!(effects.status().is_ok(), "{:?}", effects.status());
+    assert_eq!(effects.gas_cost_summary().net_gas_usage(), 0);
+
+    // The source coin is replaced by one coin per part, the last taking the remainder.
+    assert_eq!(effects.deleted().len(), 1);
+    assert_eq!(effects.deleted()[0].0, coin_refs[0].0);
+    assert!(effects.mutated().is_empty());
+    let mut values = Vec::new();
+    for ((id, _, _), owner) in effects.created() {
+        assert_eq!(owner, sui_types::object::Owner::AddressOwner(recipient));
+        let coin = state.get_object(&id).await.unwrap();
+        values.push(GasCoin::try_from(&coin).unwrap().value());
+    }
+    values.sort();
+    assert_eq!(values, vec![333, 333, 334]);
+
+    // A coin too small to give every part one MIST is left alone.
+    let tx_data = TransactionData::new_native_split_equal(sender, coin_refs[1], 3, recipient);
+    let (_cert, effects) = send_and_confirm_transaction(
+        &state,
+        None,
+        to_sender_signed_transaction(tx_data, &sender_key),
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        effects.status(),
+        &ExecutionStatus::Failure {
+            error: ExecutionFailureStatus::InsufficientCoinBalanceForTransfer {
+                available: 2,
+                requested: 3,
+            },
+            command: None,
+        }
+    );
+    assert!(effects.created().is_empty());
+    let coin = state.get_object(&coin_refs[1].0).await.unwrap();
+    assert_eq!(GasCoin::try_from(&coin).unwrap().value(), 2);
+}
+
+async fn execute_transfer_with_change(
+    amount: u64,
+    change_recipient: Option<SuiAddress>,
+) -> (SuiAddress, ObjectID, TransactionData, TransactionEffects) {
+    let (sender, sender_key) = get_account_key_pair();
+    let recipient = SuiAddress::random_for_testing_only();
+
+    let coin_id = ObjectID::random();
+    let coin_object = Object::new_move(
+        GasCoin::new(coin_id, 1000).to_object(sui_types::base_types::SequenceNumber::from_u64(1)),
+        sui_types::object::Owner::AddressOwner(sender),
+        TransactionDigest::ZERO,
+    );
+    let coin_ref = coin_object.compute_object_reference();
+
+    let state = TestAuthorityBuilder::new()
+        .with_protocol_config(protocol_config_v2())
+        .with_starting_objects(&[coin_object])
+        .build()
+        .await;
+
+    let tx_data = TransactionData::new_native_transfer_with_change(
+        sender,
+        coin_ref,
+        recipient,
+        amount,
+        change_recipient.unwrap_or(sender),
+    );
+    let signed_tx = to_sender_signed_transaction(tx_data.clone(), &sender_key);
+    let (_cert, effects) = send_and_confirm_transaction(&state, None, signed_tx)
+        .await
+        .unwrap();
+    (recipient, coin_id, tx_data, effects.into_data())
+}
+
+#[tokio::test]
+async fn test_native_transfer_with_change() {
+    let change_recipient = SuiAddress::random_for_testing_only();
+    let (recipient, coin_id, tx_data, effects) =
+        execute_transfer_with_change(300, Some(change_recipient)).await;
+    assert!(effects.status().is_ok(), "{:?}", effects.status());
+
+    // The source coin is deleted and split into one coin for each recipient.
+    assert!(effects.mutated().is_empty());
+    assert_eq!(effects.deleted().len(), 1);
+    assert_eq!(effects.deleted()[0].0, coin_id);
+    let owners: BTreeSet<_> = effects
+        .created()
+        .iter()
+        .map(|(_, owner)| owner.get_address_owner_address().unwrap())
+        .collect();
+    assert_eq!(owners, BTreeSet::from([recipient, change_recipient]));
+    assert_eq!(effects.gas_cost_summary().net_gas_usage(), 0);
+
+    // The recipient's coin is created first, so its ID is known before execution.
+    let recipient_coin = effects
+        .created()
+        .into_iter()
+        .find(|(_, owner)| owner.get_address_owner_address().unwrap() == recipient)
+        .unwrap();
+    assert_eq!(
+        Some(recipient_coin.0.0),
+        tx_data.native_transfer_created_coin_id()
+    );
+}
+
+#[tokio::test]
+async fn test_native_transfer_with_change_to_sender() {
+    // Change that goes back to the sender stays in the source coin, as in a plain transfer.
+    let (_, coin_id, tx_data, effects) = execute_transfer_with_change(300, None).await;
+    assert!(matches!(tx_data.kind(), TransactionKind::NativeTransfer(_)));
+    assert!(effects.status().is_ok(), "{:?}", effects.status());
+    assert!(effects.deleted().is_empty());
+    assert_eq!(effects.mutated().len(), 1);
+    assert_eq!(effects.mutated()[0].0.0, coin_id);
+    assert_eq!(effects.created().len(), 1);
+}
+
+#[tokio::test]
+async fn test_native_transfer_with_change_rejects_whole_coin() {
+    let change_recipient = SuiAddress::random_for_testing_only();
+    let (_, _, _, effects) = execute_transfer_with_change(1000, Some(change_recipient)).await;
+    assert_eq!(
+        effects.status(),
+        &ExecutionStatus::Failure {
+            error: ExecutionFailureStatus::NativeTransferNoChange { change_recipient },
+            command: None,
+        }
+    );
+    assert!(effects.created().is_empty());
+    assert!(effects.deleted().is_empty());
+}
+
+#[tokio::test]
+async fn test_native_transfer_created_coin_version_is_deterministic() {
+    let (sender, sender_key) = get_account_key_pair();
+    let recipient = SuiAddress::random_for_testing_only();
+
+    let coin_object = Object::new_move(
+        GasCoin::new(ObjectID::random(), 1000)
+            .to_object(sui_types::base_types::SequenceNumber::from_u64(5)),
+        sui_types::object::Owner::AddressOwner(sender),
+        TransactionDigest::ZERO,
+    );
+    let coin_ref = coin_object.compute_object_reference();
+    let tx_data = TransactionData::new_native_transfer(sender, coin_ref, recipient, 300);
+    let signed_tx = to_sender_signed_transaction(tx_data, &sender_key);
+
+    // Two independent nodes execute the same transfer.
+    let mut results = vec![];
+    for _ in 0..2 {
+        let state = TestAuthorityBuilder::new()
+            .with_protocol_config(protocol_config_v2())
+            .with_starting_objects(std::slice::from_ref(&coin_object))
+            .build()
+            .await;
+        let (_cert, effects) = send_and_confirm_transaction(&state, None, signed_tx.clone())
+            .await
+            .unwrap();
+        assert!(effects.status().is_ok(), "{:?}", effects.status());
+        let summary = state
+            .get_native_transfer_effects_batch(vec![*effects.transaction_digest()], false)
+            .pop()
+            .flatten()
+            .unwrap();
+        results.push((effects.into_data(), summary));
+    }
+
+    // The created coin takes the lamport version, one past the source coin's version.
+    let lamport_version = sui_types::base_types::SequenceNumber::from_u64(6);
+    for (effects, summary) in &results {
+        assert_eq!(effects.created()[0].0.1, lamport_version);
+        assert_eq!(summary.created_coin_version, lamport_version);
+    }
+    assert_eq!(results[0].0.created(), results[1].0.created());
+    assert_eq!(results[0].0.digest(), results[1].0.digest());
+}
+
 #[tokio::test]
 async fn test_native_transfer_multiple_transfers() {
     let (sender, sender_key) = get_account_key_pair();
@@ -436,7 +832,7 @@ async fn test_native_transfer_multiple_transfers() {
     let coin_object = Object::new_move(
         gas_coin.to_object(sui_types::base_types::SequenceNumber::from_u64(1)),
         sui_types::object::Owner::AddressOwner(sender),
-        sui_types::base_types::TransactionDigest::ZERO,
+        TransactionDigest::ZERO,
     );
     let coin_ref = coin_object.compute_object_reference();
 
@@ -547,3 +943,2451 @@ async fn test_native_transfer_multiple_transfers() {
         "Final balance should be correct after multiple transfers"
     );
 }
+
+/// Executes a native transfer of `transfer_amount` out of a coin holding `coin_value`, with the
+/// dust threshold for the source remainder set to `dust_threshold`.
+async fn execute_transfer_with_dust_threshold(
+    coin_value: u64,
+    transfer_amount: u64,
+    dust_threshold: u64,
+) -> ExecutionStatus {
+    let (sender, sender_key) = get_account_key_pair();
+    let recipient = SuiAddress::random_for_testing_only();
+
+    let gas_coin = GasCoin::new(ObjectID::random(), coin_value);
+    let coin_object = Object::new_move(
+        gas_coin.to_object(sui_types::base_types::SequenceNumber::from_u64(1)),
+        sui_types::object::Owner::AddressOwner(sender),
+        TransactionDigest::ZERO,
+    );
+    let coin_ref = coin_object.compute_object_reference();
+
+    let mut protocol_config = protocol_config_v2();
+    protocol_config.set_native_transfer_dust_threshold_for_testing(dust_threshold);
+    let state = TestAuthorityBuilder::new()
+        .with_protocol_config(protocol_config)
+        .with_starting_objects(&[coin_object])
+        .build()
+        .await;
+
+    let tx_data =
+        TransactionData::new_native_transfer(sender, coin_ref, recipient, transfer_amount);
+    let signed_tx = to_sender_signed_transaction(tx_data, &sender_key);
+    let (_cert, effects) = send_and_confirm_transaction(&state, None, signed_tx)
+        .await
+        .unwrap();
+    effects.status().clone()
+}
+
+#[tokio::test]
+async fn test_native_transfer_remainder_above_dust_threshold() {
+    // Leaves 200 MIST behind, which is above the threshold of 100.
+    let status = execute_transfer_with_dust_threshold(1000, 800, 100).await;
+    assert!(status.is_ok(), "Transfer should succeed: {:?}", status);
+}
+
+#[tokio::test]
+async fn test_native_transfer_remainder_below_dust_threshold() {
+    // Leaves 50 MIST behind, which is below the threshold of 100.
+    let status = execute_transfer_with_dust_threshold(1000, 950, 100).await;
+    match status {
+        ExecutionStatus::Failure {
+            error:
+                ExecutionFailureStatus::NativeTransferDustRemainder {
+                    remainder,
+                    threshold,
+                },
+            ..
+        } => {
+            assert_eq!(remainder, 50);
+            assert_eq!(threshold, 100);
+        }
+        status => panic!(
+            "Should fail with NativeTransferDustRemainder, got: {:?}",
+            status
+        ),
+    }
+}
+
+#[tokio::test]
+async fn test_native_transfer_full_consume_ignores_dust_threshold() {
+    // Consuming the whole coin leaves no remainder, so the threshold never applies.
+    let status = execute_transfer_with_dust_threshold(1000, 1000, 100).await;
+    assert!(status.is_ok(), "Full transfer should succeed: {:?}", status);
+}
+
+#[tokio::test]
+async fn test_native_transfer_effects_batch() {
+    let (sender, sender_key) = get_account_key_pair();
+    let recipient1 = SuiAddress::random_for_testing_only();
+    let recipient2 = SuiAddress::random_for_testing_only();
+
+    let coin_id = ObjectID::random();
+    let gas_coin = GasCoin::new(coin_id, 2000);
+    let coin_object = Object::new_move(
+        gas_coin.to_object(sui_types::base_types::SequenceNumber::from_u64(1)),
+        sui_types::object::Owner::AddressOwner(sender),
+        TransactionDigest::ZERO,
+    );
+    let coin_ref = coin_object.compute_object_reference();
+
+    let state = TestAuthorityBuilder::new()
+        .with_protocol_config(protocol_config_v2())
+        .with_starting_objects(&[coin_object])
+        .build()
+        .await;
+
+    let tx_data1 = TransactionData::new_native_transfer(sender, coin_ref, recipient1, 500);
+    let signed_tx1 = to_sender_signed_transaction(tx_data1, &sender_key);
+    let (_cert1, effects1) = send_and_confirm_transaction(&state, None, signed_tx1)
+        .await
+        .unwrap();
+    assert!(effects1.status().is_ok());
+
+    let updated_coin_ref = state
+        .get_object(&coin_id)
+        .await
+        .unwrap()
+        .compute_object_reference();
+    let tx_data2 = TransactionData::new_native_transfer(sender, updated_coin_ref, recipient2, 300);
+    let signed_tx2 = to_sender_signed_transaction(tx_data2, &sender_key);
+    let (_cert2, effects2) = send_and_confirm_transaction(&state, None, signed_tx2)
+        .await
+        .unwrap();
+    assert!(effects2.status().is_ok());
+
+    let digest1 = *effects1.transaction_digest();
+    let digest2 = *effects2.transaction_digest();
+    let unknown = TransactionDigest::random();
+    let summaries =
+        state.get_native_transfer_effects_batch(vec![digest2, unknown, digest1, unknown], false);
+    assert_eq!(summaries.len(), 4);
+    assert!(summaries[1].is_none());
+    assert!(summaries[3].is_none());
+
+    // Each summary reflects the coins as written by its own transaction.
+    assert_eq!(
+        summaries[0],
+        Some(NativeTransferSummary {
+            transaction_digest: digest2,
+            sender,
+            recipient: recipient2,
+            source_coin: coin_id,
+            source_remaining: 1200,
+            created_coin: effects2.created()[0].0.0,
+            created_coin_version: effects2.created()[0].0.1,
+            recipient_amount: 300,
+            coin_type: GAS::type_tag(),
+            source_balances: None,
+        })
+    );
+    assert_eq!(
+        summaries[2],
+        Some(NativeTransferSummary {
+            transaction_digest: digest1,
+            sender,
+            recipient: recipient1,
+            source_coin: coin_id,
+            source_remaining: 1500,
+            created_coin: effects1.created()[0].0.0,
+            created_coin_version: effects1.created()[0].0.1,
+            recipient_amount: 500,
+            coin_type: GAS::type_tag(),
+            source_balances: None,
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_native_transfer_effects_batch_source_balances() {
+    let (sender, sender_key) = get_account_key_pair();
+    let recipient = SuiAddress::random_for_testing_only();
+
+    let coin_id = ObjectID::random();
+    let coin_object = Object::new_move(
+        GasCoin::new(coin_id, 2000).to_object(sui_types::base_types::SequenceNumber::from_u64(1)),
+        sui_types::object::Owner::AddressOwner(sender),
+        TransactionDigest::ZERO,
+    );
+
+    let state = TestAuthorityBuilder::new()
+        .with_protocol_config(protocol_config_v2())
+        .with_starting_objects(&[coin_object])
+        .build()
+        .await;
+
+    // A partial transfer, then one that consumes the rest of the coin.
+    let mut digests = vec![];
+    for amount in [500, 1500] {
+        let coin_ref = state
+            .get_object(&coin_id)
+            .await
+            .unwrap()
+            .compute_object_reference();
+        let tx_data = TransactionData::new_native_transfer(sender, coin_ref, recipient, amount);
+        let signed_tx = to_sender_signed_transaction(tx_data, &sender_key);
+        let (_cert, effects) = send_and_confirm_transaction(&state, None, signed_tx)
+            .await
+            .unwrap();
+        assert!(effects.status().is_ok());
+        digests.push(*effects.transaction_digest());
+    }
+
+    let summaries = state.get_native_transfer_effects_batch(digests.clone(), true);
+    let partial = summaries[0].as_ref().unwrap().source_balances.unwrap();
+    assert_eq!(partial.pre_balance - 500, partial.post_balance);
+    assert_eq!(partial.delta, 500);
+    let full = summaries[1].as_ref().unwrap().source_balances.unwrap();
+    assert_eq!((full.pre_balance, full.post_balance), (1500, 0));
+    assert_eq!(full.delta, 1500);
+
+    // Without the flag the balances are left out.
+    assert!(
+        state
+            .get_native_transfer_effects_batch(digests, false)
+            .iter()
+            .all(|summary| summary.as_ref().unwrap().source_balances.is_none())
+    );
+}
+
+/// Sends a native transfer whose `ValidDuring` expiration names `chain`, or the authority's own
+/// chain identifier when `chain` is `None`.
+async fn send_transfer_valid_during_chain(
+    chain: Option<ChainIdentifier>,
+) -> SuiResult<SignedTransactionEffects> {
+    let (sender, sender_key) = get_account_key_pair();
+    let recipient = SuiAddress::random_for_testing_only();
+
+    let gas_coin = GasCoin::new(ObjectID::random(), 1000);
+    let coin_object = Object::new_move(
+        gas_coin.to_object(sui_types::base_types::SequenceNumber::from_u64(1)),
+        sui_types::object::Owner::AddressOwner(sender),
+        TransactionDigest::ZERO,
+    );
+    let coin_ref = coin_object.compute_object_reference();
+
+    let state = TestAuthorityBuilder::new()
+        .with_protocol_config(protocol_config_v2())
+        .with_starting_objects(&[coin_object])
+        .build()
+        .await;
+    let epoch = state.epoch_store_for_testing().epoch();
+
+    let mut tx_data = TransactionData::new_native_transfer(sender, coin_ref, recipient, 500);
+    *tx_data.expiration_mut_for_testing() = TransactionExpiration::ValidDuring {
+        min_epoch: Some(epoch),
+        max_epoch: Some(epoch),
+        min_timestamp_seconds: None,
+        max_timestamp_seconds: None,
+        chain: chain.unwrap_or_else(|| state.get_chain_identifier()),
+        nonce: 0,
+    };
+    let signed_tx = to_sender_signed_transaction(tx_data, &sender_key);
+    send_and_confirm_transaction(&state, None, signed_tx)
+        .await
+        .map(|(_cert, effects)| effects)
+}
+
+#[tokio::test]
+async fn test_native_transfer_matching_chain_identifier() {
+    let effects = send_transfer_valid_during_chain(None).await.unwrap();
+    assert!(effects.status().is_ok());
+}
+
+#[tokio::test]
+async fn test_native_transfer_mismatched_chain_identifier() {
+    let other_chain = ChainIdentifier::from(CheckpointDigest::random());
+    let err = send_transfer_valid_during_chain(Some(other_chain))
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(
+            err.as_inner(),
+            SuiErrorKind::UserInputError {
+                error: UserInputError::InvalidChainId { .. }
+            }
+        ),
+        "Should be rejected with InvalidChainId, got: {:?}",
+        err
+    );
+}
+
+/// Sends a native transfer that requires the recipient's consent, signed by the sender and/or
+/// the recipient as requested.
+async fn send_transfer_with_recipient_consent(
+    sign_as_sender: bool,
+    sign_as_recipient: bool,
+) -> SuiResult<SignedTransactionEffects> {
+    let (sender, sender_key) = get_account_key_pair();
+    let (recipient, recipient_key) = get_account_key_pair();
+
+    let gas_coin = GasCoin::new(ObjectID::random(), 1000);
+    let coin_object = Object::new_move(
+        gas_coin.to_object(sui_types::base_types::SequenceNumber::from_u64(1)),
+        sui_types::object::Owner::AddressOwner(sender),
+        TransactionDigest::ZERO,
+    );
+    let coin_ref = coin_object.compute_object_reference();
+
+    let state = TestAuthorityBuilder::new()
+        .with_protocol_config(protocol_config_v2())
+        .with_starting_objects(&[coin_object])
+        .build()
+        .await;
+
+    let tx_data = TransactionData::new_native_transfer_with_recipient_consent(
+        sender, coin_ref, recipient, 500,
+    );
+    let mut signers: Vec<&dyn Signer<Signature>> = vec![];
+    if sign_as_sender {
+        signers.push(&sender_key);
+    }
+    if sign_as_recipient {
+        signers.push(&recipient_key);
+    }
+    let signed_tx = to_sender_signed_transaction_with_multi_signers(tx_data, signers);
+    send_and_confirm_transaction(&state, None, signed_tx)
+        .await
+        .map(|(_cert, effects)| effects)
+}
+
+#[tokio::test]
+async fn test_native_transfer_recipient_consent_both_signed() {
+    let effects = send_transfer_with_recipient_consent(true, true)
+        .await
+        .unwrap();
+    assert!(effects.status().is_ok());
+    assert_eq!(effects.created().len(), 1);
+}
+
+#[tokio::test]
+async fn test_native_transfer_recipient_consent_sender_only() {
+    let err = send_transfer_with_recipient_consent(true, false)
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(
+            err.as_inner(),
+            SuiErrorKind::SignerSignatureNumberMismatch {
+                expected: 2,
+                actual: 1
+            }
+        ),
+        "Should require the recipient's signature, got: {:?}",
+        err
+    );
+}
+
+#[tokio::test]
+async fn test_native_transfer_recipient_consent_recipient_only() {
+    let err = send_transfer_with_recipient_consent(false, true)
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(
+            err.as_inner(),
+            SuiErrorKind::SignerSignatureNumberMismatch {
+                expected: 2,
+                actual: 1
+            }
+        ),
+        "Should require the sender's signature, got: {:?}",
+        err
+    );
+}
+
+#[tokio::test]
+async fn test_native_transfer_sponsored() {
+    let (sender, sender_key) = get_account_key_pair();
+    let (sponsor, sponsor_key) = get_account_key_pair();
+    let recipient = SuiAddress::random_for_testing_only();
+
+    let coin_object = Object::new_move(
+        GasCoin::new(ObjectID::random(), 1000)
+            .to_object(sui_types::base_types::SequenceNumber::from_u64(1)),
+        sui_types::object::Owner::AddressOwner(sender),
+        TransactionDigest::ZERO,
+    );
+    let coin_ref = coin_object.compute_object_reference();
+
+    let state = TestAuthorityBuilder::new()
+        .with_protocol_config(protocol_config_v2())
+        .with_starting_objects(&[coin_object])
+        .build()
+        .await;
+
+    let tx_data =
+        TransactionData::new_native_transfer_sponsored(sender, sponsor, coin_ref, recipient, 500);
+
+    // The sponsor must co-sign.
+    let signed_tx = to_sender_signed_transaction(tx_data.clone(), &sender_key);
+    let err = send_and_confirm_transaction(&state, None, signed_tx)
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(
+            err.as_inner(),
+            SuiErrorKind::SignerSignatureNumberMismatch {
+                expected: 2,
+                actual: 1
+            }
+        ),
+        "Should require the sponsor's signature, got: {:?}",
+        err
+    );
+
+    let signed_tx =
+        to_sender_signed_transaction_with_multi_signers(tx_data, vec![&sender_key, &sponsor_key]);
+    let (cert, effects) = send_and_confirm_transaction(&state, None, signed_tx)
+        .await
+        .unwrap();
+    assert!(effects.status().is_ok(), "{:?}", effects.status());
+    assert_eq!(cert.data().transaction_data().gas_owner(), sponsor);
+    assert_eq!(effects.gas_cost_summary().net_gas_usage(), 0);
+    assert_eq!(effects.created().len(), 1);
+}
+
+/// Tops up a coin holding `target_value` to `target_balance` from a coin holding `source_value`,
+/// returning the effects and the resulting source and target balances.
+async fn execute_top_up(
+    source_value: u64,
+    target_value: u64,
+    target_balance: u64,
+) -> (SignedTransactionEffects, u64, u64) {
+    let (sender, sender_key) = get_account_key_pair();
+
+    let new_coin = |value| {
+        Object::new_move(
+            GasCoin::new(ObjectID::random(), value)
+                .to_object(sui_types::base_types::SequenceNumber::from_u64(1)),
+            sui_types::object::Owner::AddressOwner(sender),
+            TransactionDigest::ZERO,
+        )
+    };
+    let source_object = new_coin(source_value);
+    let target_object = new_coin(target_value);
+    let source_ref = source_object.compute_object_reference();
+    let target_ref = target_object.compute_object_reference();
+
+    let state = TestAuthorityBuilder::new()
+        .with_protocol_config(protocol_config_v2())
+        .with_starting_objects(&[source_object, target_object])
+        .build()
+        .await;
+
+    let tx_data =
+        TransactionData::new_native_transfer_topup(sender, source_ref, target_ref, target_balance);
+    let signed_tx = to_sender_signed_transaction(tx_data, &sender_key);
+    let (_cert, effects) = send_and_confirm_transaction(&state, None, signed_tx)
+        .await
+        .unwrap();
+
+    let balance = |object: Object| GasCoin::try_from(&object).unwrap().value();
+    let source_after = balance(state.get_object(&source_ref.0).await.unwrap());
+    let target_after = balance(state.get_object(&target_ref.0).await.unwrap());
+    (effects, source_after, target_after)
+}
+
+#[tokio::test]
+async fn test_native_top_up() {
+    let (effects, source_after, target_after) = execute_top_up(1000, 200, 500).await;
+    assert!(effects.status().is_ok(), "{:?}", effects.status());
+    assert_eq!(effects.gas_cost_summary().net_gas_usage(), 0);
+    assert_eq!(effects.mutated().len(), 2);
+    assert!(effects.created().is_empty());
+    assert_eq!(source_after, 700);
+    assert_eq!(target_after, 500);
+}
+
+#[tokio::test]
+async fn test_native_top_up_target_already_met() {
+    let (effects, source_after, target_after) = execute_top_up(1000, 500, 500).await;
+    match effects.status() {
+        ExecutionStatus::Failure {
+            error:
+                ExecutionFailureStatus::NativeTopUpTargetAlreadyMet {
+                    balance,
+                    target_balance,
+                },
+            ..
+        } => {
+            assert_eq!(*balance, 500);
+            assert_eq!(*target_balance, 500);
+        }
+        status => panic!(
+            "Should fail with NativeTopUpTargetAlreadyMet, got: {:?}",
+            status
+        ),
+    }
+    assert_eq!(source_after, 1000);
+    assert_eq!(target_after, 500);
+}
+
+#[tokio::test]
+async fn test_native_top_up_insufficient_source() {
+    let (effects, source_after, target_after) = execute_top_up(100, 200, 500).await;
+    assert!(
+        matches!(
+            effects.status(),
+            ExecutionStatus::Failure {
+                error: ExecutionFailureStatus::InsufficientCoinBalanceForTransfer {
+                    available: 100,
+                    requested: 300,
+                },
+                ..
+            }
+        ),
+        "Should fail with InsufficientCoinBalanceForTransfer, got: {:?}",
+        effects.status()
+    );
+    assert_eq!(source_after, 100);
+    assert_eq!(target_after, 200);
+}
+
+#[tokio::test]
+async fn test_native_transfer_lineage() {
+    let (sender, sender_key) = get_account_key_pair();
+
+    let coin_id = ObjectID::random();
+    let coin_object = Object::new_move(
+        GasCoin::new(coin_id, 2000).to_object(sui_types::base_types::SequenceNumber::from_u64(1)),
+        sui_types::object::Owner::AddressOwner(sender),
+        TransactionDigest::ZERO,
+    );
+    let mut coin_ref = coin_object.compute_object_reference();
+
+    let state = TestAuthorityBuilder::new()
+        .with_protocol_config(protocol_config_v2())
+        .with_starting_objects(&[coin_object])
+        .build()
+        .await;
+
+    let mut expected = vec![];
+    let mut balance = 2000;
+    for amount in [500, 300, 200] {
+        let tx_data = TransactionData::new_native_transfer(
+            sender,
+            coin_ref,
+            SuiAddress::random_for_testing_only(),
+            amount,
+        );
+        let signed_tx = to_sender_signed_transaction(tx_data, &sender_key);
+        let (_cert, effects) = send_and_confirm_transaction(&state, None, signed_tx)
+            .await
+            .unwrap();
+        assert!(effects.status().is_ok());
+
+        coin_ref = effects
+            .mutated()
+            .into_iter()
+            .find(|(object_ref, _)| object_ref.0 == coin_id)
+            .unwrap()
+            .0;
+        balance -= amount;
+        expected.push(NativeTransferLineageEntry {
+            version: coin_ref.1,
+            transaction_digest: *effects.transaction_digest(),
+            balance,
+        });
+    }
+
+    assert_eq!(state.get_native_transfer_lineage(coin_id), expected);
+}
+
+#[tokio::test]
+async fn test_native_transfer_effects_verify_against_live_object_set() {
+    let (sender, sender_key) = get_account_key_pair();
+    let recipient = SuiAddress::random_for_testing_only();
+
+    let coin_id = ObjectID::random();
+    let coin_object = Object::new_move(
+        GasCoin::new(coin_id, 2000).to_object(sui_types::base_types::SequenceNumber::from_u64(1)),
+        sui_types::object::Owner::AddressOwner(sender),
+        TransactionDigest::ZERO,
+    );
+
+    let state = TestAuthorityBuilder::new()
+        .with_protocol_config(protocol_config_v2())
+        .with_starting_objects(&[coin_object])
+        .build()
+        .await;
+
+    let hasher = GlobalStateHasher::new_for_tests(state.get_global_state_hash_store().clone());
+    let include_wrapped_tombstone = !state
+        .epoch_store_for_testing()
+        .protocol_config()
+        .simplified_unwrap_then_delete();
+    let mut replayed =
+        hasher.accumulate_cached_live_object_set_for_testing(include_wrapped_tombstone);
+
+    // Two successful transfers followed by one that fails for lack of balance.
+    let mut all_effects = vec![];
+    for amount in [500, 300, 5000] {
+        let coin_ref = state
+            .get_object(&coin_id)
+            .await
+            .unwrap()
+            .compute_object_reference();
+        let tx_data = TransactionData::new_native_transfer(sender, coin_ref, recipient, amount);
+        let signed_tx = to_sender_signed_transaction(tx_data, &sender_key);
+        let (_cert, effects) = send_and_confirm_transaction(&state, None, signed_tx)
+            .await
+            .unwrap();
+        all_effects.push(effects.into_data());
+    }
+
+    // Applying the recorded effects to the starting state must reproduce the live object set,
+    // which is what a node syncing this history checks.
+    replayed.union(&hasher.accumulate_effects(
+        &all_effects,
+        state.epoch_store_for_testing().protocol_config(),
+    ));
+    let live = hasher.accumulate_cached_live_object_set_for_testing(include_wrapped_tombstone);
+    assert_eq!(replayed.digest(), live.digest());
+
+    // The source coin is live at the reference recorded by the last transfer.
+    let source_ref = state
+        .get_object(&coin_id)
+        .await
+        .unwrap()
+        .compute_object_reference();
+    assert!(
+        all_effects
+            .last()
+            .unwrap()
+            .mutated()
+            .iter()
+            .any(|(object_ref, _)| *object_ref == source_ref)
+    );
+
+    // Each created coin is live at the reference recorded by the transfer that created it.
+    let created: Vec<_> = all_effects
+        .iter()
+        .flat_map(|effects| effects.created())
+        .collect();
+    assert_eq!(created.len(), 2);
+    for (created_ref, owner) in created {
+        let coin = state.get_object(&created_ref.0).await.unwrap();
+        assert_eq!(coin.compute_object_reference(), created_ref);
+        assert_eq!(owner, sui_types::object::Owner::AddressOwner(recipient));
+    }
+}
+
+/// Certifies a checkpoint over `effects` with a single-validator committee and stores it with its
+/// contents.
+fn insert_checkpoint(
+    state: &AuthorityState,
+    sequence_number: CheckpointSequenceNumber,
+    effects: &[TransactionEffects],
+) -> VerifiedCheckpoint {
+    let contents = CheckpointContents::new_with_digests_only_for_tests(
+        effects.iter().map(|effects| effects.execution_digests()),
+    );
+    let summary = CheckpointSummary::new(
+        &ProtocolConfig::get_for_max_version_UNSAFE(),
+        0,
+        sequence_number,
+        effects.len() as u64,
+        &contents,
+        None,
+        GasCostSummary::default(),
+        None,
+        0,
+        Vec::new(),
+        Vec::new(),
+    );
+    let (keys, committee) = make_committee_key_num(1, &mut StdRng::seed_from_u64(0));
+    let signed_summary = SignedCheckpointSummary::new(
+        committee.epoch,
+        summary.clone(),
+        &keys[0],
+        keys[0].public().into(),
+    );
+    let checkpoint = CertifiedCheckpointSummary::new(
+        summary,
+        vec![signed_summary.auth_sig().clone()],
+        &committee,
+    )
+    .unwrap()
+    .try_into_verified(&committee)
+    .unwrap();
+    let checkpoint_store = state.get_checkpoint_store();
+    checkpoint_store
+        .insert_checkpoint_contents(contents)
+        .unwrap();
+    checkpoint_store
+        .insert_verified_checkpoint(&checkpoint)
+        .unwrap();
+    checkpoint
+}
+
+#[tokio::test]
+async fn test_native_transfers_in_checkpoint() {
+    let (sender, sender_key) = get_account_key_pair();
+    let recipient = SuiAddress::random_for_testing_only();
+
+    let new_coin = |value| {
+        Object::new_move(
+            GasCoin::new(ObjectID::random(), value)
+                .to_object(sui_types::base_types::SequenceNumber::from_u64(1)),
+            sui_types::object::Owner::AddressOwner(sender),
+            TransactionDigest::ZERO,
+        )
+    };
+    let source_object = new_coin(2000);
+    let target_object = new_coin(100);
+    let source_id = source_object.id();
+    let target_id = target_object.id();
+
+    let state = TestAuthorityBuilder::new()
+        .with_protocol_config(protocol_config_v2())
+        .with_starting_objects(&[source_object, target_object])
+        .build()
+        .await;
+
+    // A transfer, a top-up, a transfer that fails for lack of balance, and another transfer.
+    let mut all_effects = vec![];
+    for step in 0..4 {
+        let source_ref = state
+            .get_object(&source_id)
+            .await
+            .unwrap()
+            .compute_object_reference();
+        let tx_data = match step {
+            0 => TransactionData::new_native_transfer(sender, source_ref, recipient, 100),
+            1 => {
+                let target_ref = state
+                    .get_object(&target_id)
+                    .await
+                    .unwrap()
+                    .compute_object_reference();
+                TransactionData::new_native_transfer_topup(sender, source_ref, target_ref, 400)
+            }
+            2 => TransactionData::new_native_transfer(sender, source_ref, recipient, 1_000_000),
+            _ => TransactionData::new_native_transfer(sender, source_ref, recipient, 400),
+        };
+        let signed_tx = to_sender_signed_transaction(tx_data, &sender_key);
+        let (_cert, effects) = send_and_confirm_transaction(&state, None, signed_tx)
+            .await
+            .unwrap();
+        all_effects.push(effects.into_data());
+    }
+    assert!(all_effects[2].status().is_err());
+
+    insert_checkpoint(&state, 1, &all_effects);
+
+    // Only the two successful transfers are listed, in checkpoint order.
+    let transfers = state.get_native_transfers_in_checkpoint(1).unwrap();
+    assert_eq!(transfers.len(), 2);
+    for ((key, transfer), (index, source_remaining, amount)) in
+        transfers.iter().zip([(0, 1900, 100), (3, 1200, 400)])
+    {
+        let effects = &all_effects[index];
+        assert_eq!(
+            *key,
+            NativeTransferSortKey {
+                checkpoint: 1,
+                transaction_index: index as u64,
+                output_index: 0,
+            }
+        );
+        assert_eq!(transfer.transaction_digest, *effects.transaction_digest());
+        assert_eq!(transfer.sender, sender);
+        assert_eq!(transfer.recipient, recipient);
+        assert_eq!(transfer.source_coin, source_id);
+        assert_eq!(transfer.source_remaining, source_remaining);
+        assert_eq!(transfer.created_coin, effects.created()[0].0.0);
+        assert_eq!(transfer.recipient_amount, amount);
+    }
+}
+
+#[tokio::test]
+async fn test_native_transfers_page_overlapping_queries() {
+    let (sender, sender_key) = get_account_key_pair();
+    let recipient = SuiAddress::random_for_testing_only();
+
+    let source_object = Object::new_move(
+        GasCoin::new(ObjectID::random(), 10_000)
+            .to_object(sui_types::base_types::SequenceNumber::from_u64(1)),
+        sui_types::object::Owner::AddressOwner(sender),
+        TransactionDigest::ZERO,
+    );
+    let source_id = source_object.id();
+
+    let state = TestAuthorityBuilder::new()
+        .with_protocol_config(protocol_config_v2())
+        .with_starting_objects(&[source_object])
+        .insert_genesis_checkpoint()
+        .build()
+        .await;
+
+    let mut all_effects = vec![];
+    for amount in 1..=5 {
+        let source_ref = state
+            .get_object(&source_id)
+            .await
+            .unwrap()
+            .compute_object_reference();
+        let signed_tx = to_sender_signed_transaction(
+            TransactionData::new_native_transfer(sender, source_ref, recipient, amount * 100),
+            &sender_key,
+        );
+        let (_cert, effects) = send_and_confirm_transaction(&state, None, signed_tx)
+            .await
+            .unwrap();
+        all_effects.push(effects.into_data());
+    }
+
+    // Three transfers land in checkpoint 1 and two in checkpoint 2.
+    let checkpoint_store = state.get_checkpoint_store();
+    let genesis = checkpoint_store
+        .get_checkpoint_by_sequence_number(0)
+        .unwrap()
+        .unwrap();
+    checkpoint_store
+        .update_highest_executed_checkpoint(&genesis)
+        .unwrap();
+    for (sequence_number, effects) in [(1, &all_effects[..3]), (2, &all_effects[3..])] {
+        let checkpoint = insert_checkpoint(&state, sequence_number, effects);
+        checkpoint_store
+            .update_highest_executed_checkpoint(&checkpoint)
+            .unwrap();
+    }
+
+    let all = state
+        .get_native_transfers_page(None, 100)
+        .unwrap()
+        .transfers;
+    let keys: Vec<_> = all.iter().map(|(key, _)| *key).collect();
+    assert_eq!(
+        keys,
+        [(1, 0), (1, 1), (1, 2), (2, 0), (2, 1)].map(|(checkpoint, transaction_index)| {
+            NativeTransferSortKey {
+                checkpoint,
+                transaction_index,
+                output_index: 0,
+            }
+        })
+    );
+    for ((_, transfer), effects) in all.iter().zip(&all_effects) {
+        assert_eq!(transfer.transaction_digest, *effects.transaction_digest());
+    }
+
+    // Following cursors page by page reproduces the full listing.
+    let mut paged = vec![];
+    let mut cursor = None;
+    loop {
+        let page = state.get_native_transfers_page(cursor, 2).unwrap();
+        if page.transfers.is_empty() {
+            break;
+        }
+        cursor = page.next_cursor;
+        paged.extend(page.transfers);
+    }
+    assert_eq!(paged, all);
+
+    // Two overlapping queries agree on the transfers they share, without gaps or repeats.
+    let first = state
+        .get_native_transfers_page(Some(keys[0]), 3)
+        .unwrap()
+        .transfers;
+    let second = state
+        .get_native_transfers_page(Some(keys[1]), 3)
+        .unwrap()
+        .transfers;
+    assert_eq!(first, all[1..4]);
+    assert_eq!(second, all[2..5]);
+    assert_eq!(first[1..], second[..2]);
+
+    // Past the last transfer, the cursor moves to the end of the last checkpoint scanned.
+    let last = state.get_native_transfers_page(Some(keys[4]), 3).unwrap();
+    assert!(last.transfers.is_empty());
+    assert_eq!(
+        last.next_cursor,
+        Some(NativeTransferSortKey::end_of_checkpoint(2))
+    );
+
+    // Once checkpoint 1 is pruned, listings start at checkpoint 2.
+    let checkpoint = checkpoint_store
+        .get_checkpoint_by_sequence_number(1)
+        .unwrap()
+        .unwrap();
+    checkpoint_store
+        .update_highest_pruned_checkpoint(&checkpoint)
+        .unwrap();
+    assert_eq!(
+        state
+            .get_native_transfers_page(None, 100)
+            .unwrap()
+            .transfers,
+        all[3..]
+    );
+}
+
+#[tokio::test]
+async fn test_total_native_transfer_amount() {
+    let (sender, sender_key) = get_account_key_pair();
+    let recipient = SuiAddress::random_for_testing_only();
+
+    let source_object = Object::new_move(
+        GasCoin::new(ObjectID::random(), 10_000)
+            .to_object(sui_types::base_types::SequenceNumber::from_u64(1)),
+        sui_types::object::Owner::AddressOwner(sender),
+        TransactionDigest::ZERO,
+    );
+    let source_id = source_object.id();
+    let whole_object = Object::new_move(
+        GasCoin::new(ObjectID::random(), 2_000)
+            .to_object(sui_types::base_types::SequenceNumber::from_u64(1)),
+        sui_types::object::Owner::AddressOwner(sender),
+        TransactionDigest::ZERO,
+    );
+    let whole_ref = whole_object.compute_object_reference();
+
+    let state = TestAuthorityBuilder::new()
+        .with_protocol_config(protocol_config_v2())
+        .with_starting_objects(&[source_object, whole_object])
+        .build()
+        .await;
+    let epoch = state.epoch_store_for_testing().epoch();
+    assert_eq!(state.get_total_native_transfer_amount(), 0);
+
+    let mut digests = vec![];
+    for amount in [100, 250, 1_000_000, 400] {
+        let source_ref = state
+            .get_object(&source_id)
+            .await
+            .unwrap()
+            .compute_object_reference();
+        let signed_tx = to_sender_signed_transaction(
+            TransactionData::new_native_transfer(sender, source_ref, recipient, amount),
+            &sender_key,
+        );
+        let (_cert, effects) = send_and_confirm_transaction(&state, None, signed_tx)
+            .await
+            .unwrap();
+        digests.push(*effects.transaction_digest());
+    }
+    // A whole-coin transfer names no amount, so the coin's value is counted.
+    let signed_tx = to_sender_signed_transaction(
+        TransactionData::new_native_transfer_whole(sender, whole_ref, recipient),
+        &sender_key,
+    );
+    let (_cert, effects) = send_and_confirm_transaction(&state, None, signed_tx)
+        .await
+        .unwrap();
+    digests.push(*effects.transaction_digest());
+
+    // Transfers count once committed; the failed 1_000_000 MIST transfer never counts. The batch
+    // for later transactions may be built before earlier ones are committed.
+    let cache_commit = state.get_cache_commit();
+    let first = cache_commit.build_db_batch(epoch, &digests[..2]);
+    let second = cache_commit.build_db_batch(epoch, &digests[2..]);
+    assert_eq!(state.get_total_native_transfer_amount(), 0);
+    cache_commit.commit_transaction_outputs(epoch, first, &digests[..2]);
+    assert_eq!(state.get_total_native_transfer_amount(), 350);
+    cache_commit.commit_transaction_outputs(epoch, second, &digests[2..]);
+    assert_eq!(state.get_total_native_transfer_amount(), 2_750);
+
+    // Committing the same transactions again, as happens on restart, does not count them twice.
+    let batch = cache_commit.build_db_batch(epoch, &digests);
+    cache_commit.commit_transaction_outputs(epoch, batch, &digests);
+    assert_eq!(state.get_total_native_transfer_amount(), 2_750);
+    assert_eq!(
+        state
+            .database_for_testing()
+            .perpetual_tables
+            .get_total_native_transfer_amount()
+            .unwrap(),
+        2_750
+    );
+}
+
+#[tokio::test]
+async fn test_native_transfer_shed_when_overloaded() {
+    assert!(!AuthorityOverloadConfig::default().shed_native_transfers_when_overloaded);
+
+    let (sender, sender_key) = get_account_key_pair();
+    let recipient = SuiAddress::random_for_testing_only();
+
+    let coin_object = Object::new_move(
+        GasCoin::new(ObjectID::random(), 1000)
+            .to_object(sui_types::base_types::SequenceNumber::from_u64(1)),
+        sui_types::object::Owner::AddressOwner(sender),
+        TransactionDigest::ZERO,
+    );
+    let coin_ref = coin_object.compute_object_reference();
+
+    let state = TestAuthorityBuilder::new()
+        .with_protocol_config(protocol_config_v2())
+        .with_authority_overload_config(AuthorityOverloadConfig {
+            shed_native_transfers_when_overloaded: true,
+            ..Default::default()
+        })
+        .with_starting_objects(&[coin_object])
+        .build()
+        .await;
+
+    let transfer = |amount| {
+        to_sender_signed_transaction(
+            TransactionData::new_native_transfer(sender, coin_ref, recipient, amount),
+            &sender_key,
+        )
+    };
+    let check_overload = |tx: &Transaction| {
+        state.check_system_overload(&NoopConsensusOverloadChecker {}, tx.data(), true)
+    };
+
+    // Overloaded, but with no sampled shedding, so only native transfers are turned away.
+    state.overload_info.set_overload(0);
+    let err = check_overload(&transfer(100)).unwrap_err();
+    assert!(matches!(
+        err.as_inner(),
+        SuiErrorKind::ValidatorOverloadedRetryAfter { .. }
+    ));
+    check_overload(&make_dummy_tx(recipient, sender, &sender_key)).unwrap();
+
+    // A transfer that was admitted before the overload still executes.
+    let (_cert, effects) = send_and_confirm_transaction(&state, None, transfer(200))
+        .await
+        .unwrap();
+    assert!(effects.status().is_ok(), "{:?}", effects.status());
+
+    state.overload_info.clear_overload();
+    check_overload(&transfer(300)).unwrap();
+}
+
+/// Asks a validator to sign a native transfer from a coin that lives at version 5, referencing
+/// the coin through `coin_ref_for`, and returns the signing error.
+async fn sign_transfer_error(coin_ref_for: impl FnOnce(ObjectRef) -> ObjectRef) -> UserInputError {
+    let (sender, sender_key) = get_account_key_pair();
+    let recipient = SuiAddress::random_for_testing_only();
+
+    let coin_object = Object::new_move(
+        GasCoin::new(ObjectID::random(), 1000)
+            .to_object(sui_types::base_types::SequenceNumber::from_u64(5)),
+        sui_types::object::Owner::AddressOwner(sender),
+        TransactionDigest::ZERO,
+    );
+    let coin_ref = coin_ref_for(coin_object.compute_object_reference());
+
+    let state = TestAuthorityBuilder::new()
+        .with_protocol_config(protocol_config_v2())
+        .with_starting_objects(&[coin_object])
+        .build()
+        .await;
+
+    let tx_data = TransactionData::new_native_transfer(sender, coin_ref, recipient, 500);
+    let signed_tx = to_sender_signed_transaction(tx_data, &sender_key);
+    let err = state
+        .handle_transaction(
+            &state.epoch_store_for_testing(),
+            VerifiedTransaction::new_unchecked(signed_tx),
+        )
+        .await
+        .unwrap_err();
+    match err.into_inner() {
+        SuiErrorKind::UserInputError { error } => error,
+        err => panic!("Expected a user input error, got: {:?}", err),
+    }
+}
+
+#[tokio::test]
+async fn test_native_transfer_references_unavailable_coin_version() {
+    // An older version that is no longer in the store, e.g. because it was pruned.
+    let error = sign_transfer_error(|(id, _, _)| {
+        (
+            id,
+            sui_types::base_types::SequenceNumber::from_u64(3),
+            ObjectDigest::random(),
+        )
+    })
+    .await;
+    assert!(
+        matches!(
+            error,
+            UserInputError::ObjectVersionUnavailableForConsumption {
+                current_version,
+                ..
+            } if current_version == sui_types::base_types::SequenceNumber::from_u64(5)
+        ),
+        "got: {:?}",
+        error
+    );
+}
+
+#[tokio::test]
+async fn test_native_transfer_references_unknown_coin() {
+    let unknown_id = ObjectID::random();
+    let error = sign_transfer_error(|(_, version, digest)| (unknown_id, version, digest)).await;
+    assert_eq!(
+        error,
+        UserInputError::ObjectNotFound {
+            object_id: unknown_id,
+            version: None,
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_native_transfer_subscription() {
+    let (sender, sender_key) = get_account_key_pair();
+    let recipient = SuiAddress::random_for_testing_only();
+    let other_recipient = SuiAddress::random_for_testing_only();
+
+    let coin_id = ObjectID::random();
+    let coin_object = Object::new_move(
+        GasCoin::new(coin_id, 2000).to_object(sui_types::base_types::SequenceNumber::from_u64(1)),
+        sui_types::object::Owner::AddressOwner(sender),
+        TransactionDigest::ZERO,
+    );
+
+    let state = TestAuthorityBuilder::new()
+        .with_protocol_config(protocol_config_v2())
+        .with_starting_objects(&[coin_object])
+        .build()
+        .await;
+
+    let mut transfers = Box::pin(
+        state
+            .subscription_handler
+            .subscribe_native_transfers(NativeTransferFilter::Recipient(recipient)),
+    );
+
+    let mut expected = vec![];
+    for (to, amount, source_remaining) in [
+        (recipient, 100, 1900),
+        (other_recipient, 200, 1700),
+        (recipient, 300, 1400),
+    ] {
+        let coin_ref = state
+            .get_object(&coin_id)
+            .await
+            .unwrap()
+            .compute_object_reference();
+        let tx_data = TransactionData::new_native_transfer(sender, coin_ref, to, amount);
+        let signed_tx = to_sender_signed_transaction(tx_data, &sender_key);
+        let (_cert, effects) = send_and_confirm_transaction(&state, None, signed_tx)
+            .await
+            .unwrap();
+        if to == recipient {
+            expected.push(NativeTransferSummary {
+                transaction_digest: *effects.transaction_digest(),
+                sender,
+                recipient,
+                source_coin: coin_id,
+                source_remaining,
+                created_coin: effects.created()[0].0.0,
+                created_coin_version: effects.created()[0].0.1,
+                recipient_amount: amount,
+                coin_type: GAS::type_tag(),
+                source_balances: None,
+            });
+        }
+    }
+
+    // Only the transfers to `recipient` are streamed, in execution order.
+    for expected in expected {
+        let summary = tokio::time::timeout(Duration::from_secs(10), transfers.next())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(summary, expected);
+    }
+}
+
+#[tokio::test]
+async fn test_native_transfers_on_same_coin_version_conflict() {
+    let (sender, sender_key) = get_account_key_pair();
+
+    let coin_object = Object::new_move(
+        GasCoin::new(ObjectID::random(), 1000)
+            .to_object(sui_types::base_types::SequenceNumber::from_u64(1)),
+        sui_types::object::Owner::AddressOwner(sender),
+        TransactionDigest::ZERO,
+    );
+    let coin_ref = coin_object.compute_object_reference();
+
+    let state = TestAuthorityBuilder::new()
+        .with_protocol_config(protocol_config_v2())
+        .with_starting_objects(&[coin_object])
+        .build()
+        .await;
+    let epoch_store = state.epoch_store_for_testing();
+
+    let transfers: Vec<_> = (1..=4)
+        .map(|amount| {
+            make_native_transfer_tx(
+                &sender_key,
+                coin_ref,
+                SuiAddress::random_for_testing_only(),
+                amount,
+            )
+        })
+        .collect();
+    let first_digest = *transfers[0].digest();
+
+    // The first transfer locks the coin version; the rest are turned away at once with a hint
+    // naming the transfer that holds the lock.
+    let mut transfers = transfers.into_iter();
+    state
+        .handle_transaction(&epoch_store, transfers.next().unwrap())
+        .await
+        .unwrap();
+    for transfer in transfers {
+        let err = state
+            .handle_transaction(&epoch_store, transfer)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.into_inner(),
+            SuiErrorKind::ObjectLockConflict {
+                obj_ref: coin_ref,
+                pending_transaction: first_digest,
+            }
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_resubmitted_native_transfer_is_not_reexecuted() {
+    let (sender, sender_key) = get_account_key_pair();
+    let (builder, coin_refs) = TestAuthorityBuilder::new()
+        .with_protocol_config(protocol_config_v2())
+        .with_gas_coins(sender, &[1000]);
+    let state = builder.build().await;
+    let epoch_store = state.epoch_store_for_testing();
+
+    let transfer = make_native_transfer_tx(
+        &sender_key,
+        coin_refs[0],
+        SuiAddress::random_for_testing_only(),
+        300,
+    );
+    let cert = VerifiedExecutableTransaction::new_from_quorum_execution(transfer.clone(), 0);
+    let (effects, _) = state
+        .try_execute_immediately(&cert, ExecutionEnv::new(), &epoch_store)
+        .await
+        .unwrap();
+    assert!(effects.status().is_ok());
+    let coin_after = state
+        .get_object(&coin_refs[0].0)
+        .await
+        .unwrap()
+        .compute_object_reference();
+
+    // Submitting the same signed transfer again returns the recorded effects instead of a new
+    // signature, even though its coin version has been consumed.
+    let response = state
+        .handle_transaction(&epoch_store, transfer)
+        .await
+        .unwrap();
+    let TransactionStatus::Executed(_, signed_effects, _) = response.status else {
+        panic!("Expected the executed status, got {:?}", response.status);
+    };
+    assert_eq!(signed_effects.digest(), effects.digest());
+
+    // Executing the certificate again is a no-op that returns the same effects.
+    let (reexecuted, _) = state
+        .try_execute_immediately(&cert, ExecutionEnv::new(), &epoch_store)
+        .await
+        .unwrap();
+    assert_eq!(reexecuted.digest(), effects.digest());
+    assert_eq!(
+        state
+            .get_object(&coin_refs[0].0)
+            .await
+            .unwrap()
+            .compute_object_reference(),
+        coin_after
+    );
+}
+
+#[tokio::test]
+async fn test_concurrent_native_transfers_on_same_coin() {
+    let (sender, sender_key) = get_account_key_pair();
+
+    let coin_object = Object::new_move(
+        GasCoin::new(ObjectID::random(), 1000)
+            .to_object(sui_types::base_types::SequenceNumber::from_u64(1)),
+        sui_types::object::Owner::AddressOwner(sender),
+        TransactionDigest::ZERO,
+    );
+    let coin_ref = coin_object.compute_object_reference();
+
+    let state = TestAuthorityBuilder::new()
+        .with_protocol_config(protocol_config_v2())
+        .with_starting_objects(&[coin_object])
+        .build()
+        .await;
+    let epoch_store = state.epoch_store_for_testing();
+
+    let first = make_native_transfer_tx(
+        &sender_key,
+        coin_ref,
+        SuiAddress::random_for_testing_only(),
+        600,
+    );
+    let second = make_native_transfer_tx(
+        &sender_key,
+        coin_ref,
+        SuiAddress::random_for_testing_only(),
+        700,
+    );
+
+    // Both transfers race for the lock on the same coin version. Lock acquisition is atomic, so
+    // exactly one of them is signed and the other names it as the lock holder.
+    let (first_result, second_result) = tokio::join!(
+        state.handle_transaction(&epoch_store, first.clone()),
+        state.handle_transaction(&epoch_store, second.clone()),
+    );
+    let (winner, err) = match (first_result, second_result) {
+        (Ok(_), Err(err)) => (first, err),
+        (Err(err), Ok(_)) => (second, err),
+        (first_result, second_result) => panic!(
+            "Expected exactly one transfer to be signed, got {:?} and {:?}",
+            first_result.map(|_| ()),
+            second_result.map(|_| ())
+        ),
+    };
+    assert_eq!(
+        err.into_inner(),
+        SuiErrorKind::ObjectLockConflict {
+            obj_ref: coin_ref,
+            pending_transaction: *winner.digest(),
+        }
+    );
+
+    // The fast path only executes what was signed, so the winner is the only transfer that
+    // spends the coin.
+    let cert = VerifiedExecutableTransaction::new_from_quorum_execution(winner, 0);
+    let (effects, _) = state
+        .try_execute_immediately(
+            &cert,
+            ExecutionEnv::new().with_scheduling_source(SchedulingSource::MysticetiFastPath),
+            &epoch_store,
+        )
+        .await
+        .unwrap();
+    assert!(effects.status().is_ok());
+    assert_eq!(effects.created().len(), 1);
+}
+
+#[tokio::test]
+async fn test_is_coin_locked() {
+    let (sender, sender_key) = get_account_key_pair();
+    let (builder, coin_refs) = TestAuthorityBuilder::new()
+        .with_protocol_config(protocol_config_v2())
+        .with_gas_coins(sender, &[1000]);
+    let state = builder.build().await;
+    let epoch_store = state.epoch_store_for_testing();
+    let coin_ref = coin_refs[0];
+    assert_eq!(state.is_coin_locked(&coin_ref), None);
+
+    // Signing a transfer locks the coin version to it.
+    let transfer = make_native_transfer_tx(
+        &sender_key,
+        coin_ref,
+        SuiAddress::random_for_testing_only(),
+        600,
+    );
+    state
+        .handle_transaction(&epoch_store, transfer.clone())
+        .await
+        .unwrap();
+    assert_eq!(state.is_coin_locked(&coin_ref), Some(*transfer.digest()));
+
+    // Once the transfer executes, the locked version is no longer live.
+    let cert = VerifiedExecutableTransaction::new_from_quorum_execution(transfer, 0);
+    let (effects, _) = state
+        .try_execute_immediately(&cert, ExecutionEnv::new(), &epoch_store)
+        .await
+        .unwrap();
+    assert!(effects.status().is_ok());
+    assert_eq!(state.is_coin_locked(&coin_ref), None);
+}
+
+#[tokio::test]
+async fn test_native_transfer_after_epoch() {
+    let (sender, sender_key) = get_account_key_pair();
+    let recipient = SuiAddress::random_for_testing_only();
+    let (builder, coin_refs) = TestAuthorityBuilder::new()
+        .with_protocol_config(protocol_config_v2())
+        .with_gas_coins(sender, &[1000]);
+    let state = builder.build().await;
+    let epoch_store = state.epoch_store_for_testing();
+    assert_eq!(epoch_store.epoch(), 0);
+
+    // Signing is refused before the target epoch.
+    let deferred = to_sender_signed_transaction(
+        TransactionData::new_native_transfer_after_epoch(sender, coin_refs[0], recipient, 300, 1),
+        &sender_key,
+    );
+    let err = deferred
+        .validity_check(&epoch_store.tx_validity_check_context())
+        .unwrap_err();
+    assert_eq!(
+        err.into_inner(),
+        SuiErrorKind::UserInputError {
+            error: UserInputError::NativeTransferNotYetExecutable {
+                not_before_epoch: 1,
+                current_epoch: 0,
+            }
+        }
+    );
+
+    // It is accepted on and after the target epoch.
+    let mut context = epoch_store.tx_validity_check_context();
+    for epoch in [1, 2] {
+        context.epoch = epoch;
+        deferred.validity_check(&context).unwrap();
+    }
+
+    // Once its epoch has arrived it executes like a plain transfer.
+    let due = to_sender_signed_transaction(
+        TransactionData::new_native_transfer_after_epoch(sender, coin_refs[0], recipient, 300, 0),
+        &sender_key,
+    );
+    due.validity_check(&epoch_store.tx_validity_check_context())
+        .unwrap();
+    let expected_coin_id = due.transaction_data().native_transfer_created_coin_id();
+    let (_cert, effects) = send_and_confirm_transaction(&state, None, due)
+        .await
+        .unwrap();
+    assert!(effects.status().is_ok(), "{:?}", effects.status());
+    assert_eq!(effects.gas_cost_summary().net_gas_usage(), 0);
+    assert_eq!(effects.created().len(), 1);
+    assert_eq!(Some(effects.created()[0].0.0), expected_coin_id);
+    let created = state.get_object(&effects.created()[0].0.0).await.unwrap();
+    assert_eq!(
+        created.owner,
+        sui_types::object::Owner::AddressOwner(recipient)
+    );
+    assert_eq!(GasCoin::try_from(&created).unwrap().value(), 300);
+    let coin = state.get_object(&coin_refs[0].0).await.unwrap();
+    assert_eq!(GasCoin::try_from(&coin).unwrap().value(), 700);
+}
+
+#[tokio::test]
+async fn test_native_transfer_respects_lock_held_by_move_transaction() {
+    let (sender, sender_key) = get_account_key_pair();
+    let recipient = SuiAddress::random_for_testing_only();
+
+    let coin_object = Object::new_move(
+        GasCoin::new(ObjectID::random(), 1_000_000_000_000)
+            .to_object(sui_types::base_types::SequenceNumber::from_u64(1)),
+        sui_types::object::Owner::AddressOwner(sender),
+        TransactionDigest::ZERO,
+    );
+    let coin_ref = coin_object.compute_object_reference();
+
+    let state = TestAuthorityBuilder::new()
+        .with_protocol_config(protocol_config_v2())
+        .with_starting_objects(&[coin_object])
+        .build()
+        .await;
+    let epoch_store = state.epoch_store_for_testing();
+
+    // A Move transaction paying gas with the coin is signed but not yet executed, so it holds
+    // the lock on the coin version.
+    let move_tx = VerifiedTransaction::new_unchecked(to_sender_signed_transaction(
+        TransactionData::new_transfer_sui(
+            recipient,
+            sender,
+            Some(100),
+            coin_ref,
+            10_000_000,
+            state.reference_gas_price_for_testing().unwrap(),
+        ),
+        &sender_key,
+    ));
+    let move_digest = *move_tx.digest();
+    state
+        .handle_transaction(&epoch_store, move_tx)
+        .await
+        .unwrap();
+
+    let transfer = make_native_transfer_tx(&sender_key, coin_ref, recipient, 100);
+    let err = state
+        .handle_transaction(&epoch_store, transfer)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.into_inner(),
+        SuiErrorKind::ObjectLockConflict {
+            obj_ref: coin_ref,
+            pending_transaction: move_digest,
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_native_transfer_rejected_near_epoch_end() {
+    assert!(
+        AuthorityOverloadConfig::default()
+            .native_transfer_epoch_end_window
+            .is_none()
+    );
+
+    let (sender, sender_key) = get_account_key_pair();
+    let recipient = SuiAddress::random_for_testing_only();
+
+    let new_coin = |value| {
+        Object::new_move(
+            GasCoin::new(ObjectID::random(), value)
+                .to_object(sui_types::base_types::SequenceNumber::from_u64(1)),
+            sui_types::object::Owner::AddressOwner(sender),
+            TransactionDigest::ZERO,
+        )
+    };
+    let coin_object = new_coin(1000);
+    let gas_object = new_coin(1_000_000_000_000);
+    let coin_ref = coin_object.compute_object_reference();
+    let gas_ref = gas_object.compute_object_reference();
+
+    // A window longer than any epoch puts every moment inside it.
+    let state = TestAuthorityBuilder::new()
+        .with_protocol_config(protocol_config_v2())
+        .with_authority_overload_config(AuthorityOverloadConfig {
+            native_transfer_epoch_end_window: Some(Duration::MAX),
+            ..Default::default()
+        })
+        .with_starting_objects(&[coin_object, gas_object])
+        .build()
+        .await;
+    let epoch_store = state.epoch_store_for_testing();
+
+    let transfer = make_native_transfer_tx(&sender_key, coin_ref, recipient, 100);
+    let err = state
+        .handle_transaction(&epoch_store, transfer)
+        .await
+        .unwrap_err()
+        .into_inner();
+    assert_eq!(err, SuiErrorKind::ValidatorHaltedAtEpochEnd);
+    assert_eq!(err.is_retryable(), (true, true));
+
+    // Other transactions are still admitted.
+    let move_tx = VerifiedTransaction::new_unchecked(to_sender_signed_transaction(
+        TransactionData::new_transfer_sui(
+            recipient,
+            sender,
+            Some(100),
+            gas_ref,
+            10_000_000,
+            state.reference_gas_price_for_testing().unwrap(),
+        ),
+        &sender_key,
+    ));
+    state
+        .handle_transaction(&epoch_store, move_tx)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_native_transfer_reconciler_flags_non_conserving_transfer() {
+    let (sender, sender_key) = get_account_key_pair();
+    let recipient = SuiAddress::random_for_testing_only();
+
+    let coin_object = Object::new_move(
+        GasCoin::new(ObjectID::random(), 2000)
+            .to_object(sui_types::base_types::SequenceNumber::from_u64(1)),
+        sui_types::object::Owner::AddressOwner(sender),
+        TransactionDigest::ZERO,
+    );
+    let coin_ref = coin_object.compute_object_reference();
+
+    let state = TestAuthorityBuilder::new()
+        .with_protocol_config(protocol_config_v2())
+        .with_starting_objects(&[coin_object])
+        .build()
+        .await;
+    let epoch = state.epoch_store_for_testing().epoch();
+
+    let signed_tx = to_sender_signed_transaction(
+        TransactionData::new_native_transfer(sender, coin_ref, recipient, 500),
+        &sender_key,
+    );
+    let (_cert, effects) = send_and_confirm_transaction(&state, None, signed_tx)
+        .await
+        .unwrap();
+    let effects = effects.into_data();
+    let digests = [*effects.transaction_digest()];
+    let cache_commit = state.get_cache_commit();
+    let batch = cache_commit.build_db_batch(epoch, &digests);
+    cache_commit.commit_transaction_outputs(epoch, batch, &digests);
+    insert_checkpoint(&state, 1, std::slice::from_ref(&effects));
+
+    let violations = &state.metrics.native_transfer_conservation_violations;
+    assert!(
+        reconcile_native_transfers_in_checkpoint(&state, 1)
+            .unwrap()
+            .is_empty()
+    );
+    assert_eq!(violations.get(), 0);
+
+    // Corrupt the stored recipient coin so it holds more than left the source coin.
+    let (created_ref, created_owner) = effects.created()[0].clone();
+    let corrupted = Object::new_move(
+        GasCoin::new(created_ref.0, 501).to_object(created_ref.1),
+        created_owner,
+        digests[0],
+    );
+    state.insert_genesis_object(corrupted).await;
+
+    assert_eq!(
+        reconcile_native_transfers_in_checkpoint(&state, 1).unwrap(),
+        digests
+    );
+    assert_eq!(violations.get(), 1);
+}
+
+#[tokio::test]
+async fn test_native_transfer_reconciler_skips_unavailable_checkpoints() {
+    let state = TestAuthorityBuilder::new()
+        .with_protocol_config(protocol_config_v2())
+        .build()
+        .await;
+
+    // Checkpoint 2 is executed, but checkpoint 1 cannot be read.
+    let checkpoint = insert_checkpoint(&state, 2, &[]);
+    state
+        .get_checkpoint_store()
+        .update_highest_executed_checkpoint(&checkpoint)
+        .unwrap();
+    assert!(reconcile_native_transfers_in_checkpoint(&state, 1).is_err());
+
+    // The reconciler moves past it instead of retrying it on every round.
+    assert_eq!(reconcile_new_checkpoints(&state, Some(1)), Some(3));
+}
+
+/// Splits `amounts` out of a coin holding `coin_value` to one fresh recipient each, returning the
+/// effects, the recipients, and the remaining source balance.
+async fn execute_multi_transfer(
+    coin_value: u64,
+    amounts: &[u64],
+) -> (
+    AuthorityState,
+    SignedTransactionEffects,
+    Vec<SuiAddress>,
+    u64,
+) {
+    let (sender, sender_key) = get_account_key_pair();
+    let coin_object = Object::new_move(
+        GasCoin::new(ObjectID::random(), coin_value)
+            .to_object(sui_types::base_types::SequenceNumber::from_u64(1)),
+        sui_types::object::Owner::AddressOwner(sender),
+        TransactionDigest::ZERO,
+    );
+    let coin_ref = coin_object.compute_object_reference();
+
+    let state = TestAuthorityBuilder::new()
+        .with_protocol_config(protocol_config_v2())
+        .with_starting_objects(&[coin_object])
+        .build()
+        .await;
+
+    let recipients: Vec<_> = amounts
+        .iter()
+        .map(|amount| (SuiAddress::random_for_testing_only(), *amount))
+        .collect();
+    let tx_data = TransactionData::new_native_transfer_multi(sender, coin_ref, recipients.clone());
+    let signed_tx = to_sender_signed_transaction(tx_data, &sender_key);
+    let (_cert, effects) = send_and_confirm_transaction(&state, None, signed_tx)
+        .await
+        .unwrap();
+
+    let source = state.get_object(&coin_ref.0).await.unwrap();
+    let source_after = GasCoin::try_from(&source).unwrap().value();
+    let recipients = recipients
+        .into_iter()
+        .map(|(recipient, _)| recipient)
+        .collect();
+    (state, effects, recipients, source_after)
+}
+
+#[tokio::test]
+async fn test_native_multi_transfer() {
+    let amounts = [300, 200, 100];
+    let (state, effects, recipients, source_after) = execute_multi_transfer(2000, &amounts).await;
+    assert!(effects.status().is_ok(), "{:?}", effects.status());
+    assert_eq!(effects.gas_cost_summary().net_gas_usage(), 0);
+    assert_eq!(effects.mutated().len(), 1);
+    assert_eq!(effects.created().len(), amounts.len());
+    assert_eq!(source_after, 1400);
+
+    for (recipient, amount) in recipients.iter().zip(amounts) {
+        let ((created_id, _, _), _) = effects
+            .created()
+            .into_iter()
+            .find(|(_, owner)| owner.get_address_owner_address().unwrap() == *recipient)
+            .expect("Each recipient should own a created coin");
+        let created = state.get_object(&created_id).await.unwrap();
+        assert_eq!(GasCoin::try_from(&created).unwrap().value(), amount);
+    }
+}
+
+#[tokio::test]
+async fn test_native_multi_transfer_insufficient_balance() {
+    let (_state, effects, _recipients, source_after) =
+        execute_multi_transfer(500, &[300, 200, 100]).await;
+    assert!(
+        matches!(
+            effects.status(),
+            ExecutionStatus::Failure {
+                error: ExecutionFailureStatus::InsufficientCoinBalanceForTransfer {
+                    available: 500,
+                    requested: 600,
+                },
+                ..
+            }
+        ),
+        "Should fail with InsufficientCoinBalanceForTransfer, got: {:?}",
+        effects.status()
+    );
+    assert!(
+        effects.created().is_empty(),
+        "No recipient should be paid when the transfer fails"
+    );
+    assert_eq!(source_after, 500);
+}
+
+#[tokio::test]
+async fn test_native_multi_transfer_total_overflow() {
+    let (_state, effects, _recipients, source_after) =
+        execute_multi_transfer(u64::MAX, &[u64::MAX, 1]).await;
+    assert!(
+        matches!(
+            effects.status(),
+            ExecutionStatus::Failure {
+                error: ExecutionFailureStatus::CoinBalanceOverflow,
+                ..
+            }
+        ),
+        "Should fail with CoinBalanceOverflow, got: {:?}",
+        effects.status()
+    );
+    assert!(effects.created().is_empty());
+    assert_eq!(source_after, u64::MAX);
+
+    let (_state, effects, _recipients, source_after) =
+        execute_multi_transfer(u64::MAX, &[u64::MAX - 1, 1]).await;
+    assert!(effects.status().is_ok(), "{:?}", effects.status());
+    assert_eq!(source_after, 0);
+}
+
+#[tokio::test]
+async fn test_native_multi_transfer_effects_are_deterministic() {
+    let (sender, sender_key) = get_account_key_pair();
+    let coin_object = Object::new_move(
+        GasCoin::new(ObjectID::random(), 2000)
+            .to_object(sui_types::base_types::SequenceNumber::from_u64(1)),
+        sui_types::object::Owner::AddressOwner(sender),
+        TransactionDigest::ZERO,
+    );
+    let coin_ref = coin_object.compute_object_reference();
+
+    let recipients: Vec<_> = [300, 200, 100, 50]
+        .into_iter()
+        .map(|amount| (SuiAddress::random_for_testing_only(), amount))
+        .collect();
+    let tx_data = TransactionData::new_native_transfer_multi(sender, coin_ref, recipients.clone());
+    let expected_ids = tx_data.native_multi_transfer_created_coin_ids().unwrap();
+    let cert = VerifiedExecutableTransaction::new_from_quorum_execution(
+        VerifiedTransaction::new_unchecked(to_sender_signed_transaction(tx_data, &sender_key)),
+        0,
+    );
+
+    // Execute the same certificate on two independently built authorities.
+    let mut executions = vec![];
+    for _ in 0..2 {
+        let state = TestAuthorityBuilder::new()
+            .with_protocol_config(protocol_config_v2())
+            .with_starting_objects(&[coin_object.clone()])
+            .build()
+            .await;
+        let (effects, _) = state
+            .try_execute_immediately(&cert, ExecutionEnv::new(), &state.epoch_store_for_testing())
+            .await
+            .unwrap();
+        assert!(effects.status().is_ok(), "{:?}", effects.status());
+        executions.push((state, effects));
+    }
+    let (state, effects) = &executions[0];
+    assert_eq!(
+        bcs::to_bytes(effects).unwrap(),
+        bcs::to_bytes(&executions[1].1).unwrap()
+    );
+
+    // Effects list the created coins sorted by ID.
+    let created_ids: Vec<_> = effects.created().iter().map(|(r, _)| r.0).collect();
+    let mut sorted_ids = expected_ids.clone();
+    sorted_ids.sort();
+    assert_eq!(created_ids, sorted_ids);
+
+    // The coin for each recipient has the ID at the recipient's index.
+    for ((recipient, amount), id) in recipients.into_iter().zip(expected_ids) {
+        let coin = state.get_object(&id).await.unwrap();
+        assert_eq!(coin.owner.get_address_owner_address().unwrap(), recipient);
+        assert_eq!(GasCoin::try_from(&coin).unwrap().value(), amount);
+    }
+}
+
+#[tokio::test]
+async fn test_native_transfer_near_max_coin_value() {
+    for amount in [1, u64::MAX - 1, u64::MAX] {
+        let (sender, sender_key) = get_account_key_pair();
+        let recipient = SuiAddress::random_for_testing_only();
+        let coin_object = Object::new_move(
+            GasCoin::new(ObjectID::random(), u64::MAX)
+                .to_object(sui_types::base_types::SequenceNumber::from_u64(1)),
+            sui_types::object::Owner::AddressOwner(sender),
+            TransactionDigest::ZERO,
+        );
+        let coin_ref = coin_object.compute_object_reference();
+
+        let state = TestAuthorityBuilder::new()
+            .with_protocol_config(protocol_config_v2())
+            .with_starting_objects(&[coin_object])
+            .build()
+            .await;
+
+        let tx_data = TransactionData::new_native_transfer(sender, coin_ref, recipient, amount);
+        let signed_tx = to_sender_signed_transaction(tx_data, &sender_key);
+        let (_cert, effects) = send_and_confirm_transaction(&state, None, signed_tx)
+            .await
+            .unwrap();
+        assert!(effects.status().is_ok(), "{:?}", effects.status());
+
+        let source = state.get_object(&coin_ref.0).await.unwrap();
+        assert_eq!(
+            GasCoin::try_from(&source).unwrap().value(),
+            u64::MAX - amount
+        );
+        let created = state.get_object(&effects.created()[0].0.0).await.unwrap();
+        assert_eq!(GasCoin::try_from(&created).unwrap().value(), amount);
+    }
+}
+
+#[tokio::test]
+async fn test_native_multi_transfer_validity() {
+    let (sender, _sender_key) = get_account_key_pair();
+    let config = protocol_config_v2();
+    let coin_ref = random_object_ref();
+    let multi_transfer = |recipients| {
+        TransactionData::new_native_transfer_multi(sender, coin_ref, recipients)
+            .validity_check(&config)
+    };
+
+    let recipient = SuiAddress::random_for_testing_only();
+    assert!(multi_transfer(vec![(recipient, 100)]).is_ok());
+    assert!(
+        multi_transfer(vec![]).is_err(),
+        "Should fail validation without recipients"
+    );
+    assert!(
+        multi_transfer(vec![(recipient, 100), (recipient, 0)]).is_err(),
+        "Should fail validation for a zero amount"
+    );
+}
+
+/// Merges `coins_to_merge`, owned by a fresh sender, into a gas coin holding `primary_value`,
+/// returning the effects and the primary coin's ID.
+async fn execute_merge(
+    primary_value: u64,
+    coins_to_merge: impl FnOnce(SuiAddress) -> Vec<Object>,
+) -> (AuthorityState, SignedTransactionEffects, ObjectID) {
+    let (sender, sender_key) = get_account_key_pair();
+    let primary_object = Object::new_move(
+        GasCoin::new(ObjectID::random(), primary_value)
+            .to_object(sui_types::base_types::SequenceNumber::from_u64(1)),
+        sui_types::object::Owner::AddressOwner(sender),
+        TransactionDigest::ZERO,
+    );
+    let primary_ref = primary_object.compute_object_reference();
+    let coins_to_merge = coins_to_merge(sender);
+    let merge_refs = coins_to_merge
+        .iter()
+        .map(|coin| coin.compute_object_reference())
+        .collect();
+
+    let mut starting_objects = coins_to_merge;
+    starting_objects.push(primary_object);
+    let state = TestAuthorityBuilder::new()
+        .with_protocol_config(protocol_config_v2())
+        .with_starting_objects(&starting_objects)
+        .build()
+        .await;
+
+    let tx_data = TransactionData::new_native_merge(sender, primary_ref, merge_refs);
+    let signed_tx = to_sender_signed_transaction(tx_data, &sender_key);
+    let (_cert, effects) = send_and_confirm_transaction(&state, None, signed_tx)
+        .await
+        .unwrap();
+    (state, effects, primary_ref.0)
+}
+
+fn owned_coin(owner: SuiAddress, coin: MoveObject) -> Object {
+    Object::new_move(
+        coin,
+        sui_types::object::Owner::AddressOwner(owner),
+        TransactionDigest::ZERO,
+    )
+}
+
+#[tokio::test]
+async fn test_native_merge() {
+    let mut merged_ids = vec![];
+    let (state, effects, primary_id) = execute_merge(1000, |sender| {
+        [200, 300]
+            .into_iter()
+            .map(|value| {
+                let coin = GasCoin::new(ObjectID::random(), value)
+                    .to_object(sui_types::base_types::SequenceNumber::from_u64(1));
+                merged_ids.push(coin.id());
+                owned_coin(sender, coin)
+            })
+            .collect()
+    })
+    .await;
+    assert!(effects.status().is_ok(), "{:?}", effects.status());
+    assert_eq!(effects.gas_cost_summary().net_gas_usage(), 0);
+    assert!(effects.created().is_empty());
+
+    let mutated: Vec<_> = effects
+        .mutated()
+        .iter()
+        .map(|(obj_ref, _)| obj_ref.0)
+        .collect();
+    assert_eq!(mutated, vec![primary_id]);
+    let mut deleted: Vec<_> = effects.deleted().iter().map(|obj_ref| obj_ref.0).collect();
+    deleted.sort();
+    merged_ids.sort();
+    assert_eq!(deleted, merged_ids);
+
+    let primary = state.get_object(&primary_id).await.unwrap();
+    assert_eq!(GasCoin::try_from(&primary).unwrap().value(), 1500);
+    for id in &merged_ids {
+        assert!(state.get_object(id).await.is_none());
+    }
+}
+
+#[tokio::test]
+async fn test_native_merge_rejects_non_gas_coin() {
+    let other_coin_type = sui_types::parse_sui_type_tag("0x42::example::EXAMPLE").unwrap();
+    let mut other_coin_id = None;
+    let (state, effects, primary_id) = execute_merge(1000, |sender| {
+        let coin = MoveObject::new_coin(
+            other_coin_type,
+            sui_types::base_types::SequenceNumber::from_u64(1),
+            ObjectID::random(),
+            500,
+        );
+        other_coin_id = Some(coin.id());
+        vec![owned_coin(sender, coin)]
+    })
+    .await;
+    let other_coin_id = other_coin_id.unwrap();
+
+    match effects.status() {
+        ExecutionStatus::Failure {
+            error: ExecutionFailureStatus::InvalidMergeInput { id },
+            ..
+        } => assert_eq!(*id, other_coin_id),
+        status => panic!("Should fail with InvalidMergeInput, got: {:?}", status),
+    }
+    assert!(effects.deleted().is_empty());
+    let primary = state.get_object(&primary_id).await.unwrap();
+    assert_eq!(GasCoin::try_from(&primary).unwrap().value(), 1000);
+    assert!(state.get_object(&other_coin_id).await.is_some());
+}
+
+#[tokio::test]
+async fn test_native_transfer_rejects_non_coin_source() {
+    let (sender, sender_key) = get_account_key_pair();
+    let recipient = SuiAddress::random_for_testing_only();
+
+    // A Move object owned by the sender that is not a coin.
+    let cap = Object::treasury_cap_for_testing(
+        sui_types::parse_sui_struct_tag("0x42::example::EXAMPLE").unwrap(),
+        TreasuryCap {
+            id: UID::new(ObjectID::random()),
+            total_supply: Supply { value: 0 },
+        },
+    );
+    let cap_object = Object::new_move(
+        cap.data.try_as_move().unwrap().clone(),
+        sui_types::object::Owner::AddressOwner(sender),
+        TransactionDigest::ZERO,
+    );
+    let cap_ref = cap_object.compute_object_reference();
+
+    let state = TestAuthorityBuilder::new()
+        .with_protocol_config(protocol_config_v2())
+        .with_starting_objects(&[cap_object])
+        .build()
+        .await;
+
+    let tx_data = TransactionData::new_native_transfer(sender, cap_ref, recipient, 100);
+    let signed_tx = to_sender_signed_transaction(tx_data, &sender_key);
+    let (_cert, effects) = send_and_confirm_transaction(&state, None, signed_tx)
+        .await
+        .unwrap();
+    assert_eq!(
+        effects.status(),
+        &ExecutionStatus::Failure {
+            error: ExecutionFailureStatus::NativeTransferInputNotACoin {
+                object_id: cap_ref.0
+            },
+            command: None,
+        }
+    );
+    assert!(effects.created().is_empty());
+
+    // Packages are not even accepted as inputs.
+    let package_ref = state
+        .get_object(&SUI_FRAMEWORK_PACKAGE_ID)
+        .await
+        .unwrap()
+        .compute_object_reference();
+    let tx_data = TransactionData::new_native_transfer(sender, package_ref, recipient, 100);
+    let signed_tx = to_sender_signed_transaction(tx_data, &sender_key);
+    let err = send_and_confirm_transaction(&state, None, signed_tx)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.into_inner(),
+        SuiErrorKind::UserInputError {
+            error: UserInputError::MovePackageAsObject {
+                object_id: SUI_FRAMEWORK_PACKAGE_ID
+            }
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_native_transfer_non_coin_source_without_status_flag() {
+    let (sender, sender_key) = get_account_key_pair();
+    let recipient = SuiAddress::random_for_testing_only();
+
+    let cap = Object::treasury_cap_for_testing(
+        sui_types::parse_sui_struct_tag("0x42::example::EXAMPLE").unwrap(),
+        TreasuryCap {
+            id: UID::new(ObjectID::random()),
+            total_supply: Supply { value: 0 },
+        },
+    );
+    let cap_object = Object::new_move(
+        cap.data.try_as_move().unwrap().clone(),
+        sui_types::object::Owner::AddressOwner(sender),
+        TransactionDigest::ZERO,
+    );
+    let cap_ref = cap_object.compute_object_reference();
+
+    // Without the flag, a non-coin input keeps failing with the status it always had.
+    let mut protocol_config = protocol_config_v2();
+    protocol_config.set_native_transfer_not_a_coin_status_for_testing(false);
+    let state = TestAuthorityBuilder::new()
+        .with_protocol_config(protocol_config)
+        .with_starting_objects(&[cap_object])
+        .build()
+        .await;
+
+    let tx_data = TransactionData::new_native_transfer(sender, cap_ref, recipient, 100);
+    let signed_tx = to_sender_signed_transaction(tx_data, &sender_key);
+    let (_cert, effects) = send_and_confirm_transaction(&state, None, signed_tx)
+        .await
+        .unwrap();
+    assert_eq!(
+        effects.status(),
+        &ExecutionStatus::Failure {
+            error: ExecutionFailureStatus::InvalidGasObject,
+            command: None,
+        }
+    );
+    assert!(effects.created().is_empty());
+}
+
+#[tokio::test]
+async fn test_dry_run_native_transfer() {
+    let (sender, sender_key) = get_account_key_pair();
+    let recipient = SuiAddress::random_for_testing_only();
+
+    let coin_object = Object::new_move(
+        GasCoin::new(ObjectID::random(), 1000)
+            .to_object(sui_types::base_types::SequenceNumber::from_u64(1)),
+        sui_types::object::Owner::AddressOwner(sender),
+        TransactionDigest::ZERO,
+    );
+    let coin_ref = coin_object.compute_object_reference();
+
+    let state = TestAuthorityBuilder::new()
+        .with_protocol_config(protocol_config_v2())
+        .with_starting_objects(&[coin_object])
+        .build()
+        .await;
+
+    let tx_data = TransactionData::new_native_transfer(sender, coin_ref, recipient, 300);
+    let preview = state.dry_run_native_transfer(&tx_data).unwrap().unwrap();
+    assert_eq!(preview.source_remaining, 700);
+    assert_eq!(preview.recipient_amount, 300);
+    assert_eq!(
+        Some(preview.created_coin),
+        tx_data.native_transfer_created_coin_id()
+    );
+
+    // The dry run leaves the coin untouched, so the transfer can still be executed for real.
+    let coin = state.get_object(&coin_ref.0).await.unwrap();
+    assert_eq!(coin.compute_object_reference(), coin_ref);
+
+    let signed_tx = to_sender_signed_transaction(tx_data, &sender_key);
+    let (_cert, effects) = send_and_confirm_transaction(&state, None, signed_tx)
+        .await
+        .unwrap();
+    assert!(effects.status().is_ok(), "{:?}", effects.status());
+    let executed = state
+        .get_native_transfer_effects_batch(vec![*effects.transaction_digest()], false)
+        .pop()
+        .flatten()
+        .unwrap();
+    assert_eq!(preview, executed);
+}
+
+#[tokio::test]
+async fn test_dry_run_native_transfer_insufficient_balance() {
+    let (sender, _sender_key) = get_account_key_pair();
+
+    let coin_object = Object::new_move(
+        GasCoin::new(ObjectID::random(), 1000)
+            .to_object(sui_types::base_types::SequenceNumber::from_u64(1)),
+        sui_types::object::Owner::AddressOwner(sender),
+        TransactionDigest::ZERO,
+    );
+    let coin_ref = coin_object.compute_object_reference();
+
+    let state = TestAuthorityBuilder::new()
+        .with_protocol_config(protocol_config_v2())
+        .with_starting_objects(&[coin_object])
+        .build()
+        .await;
+
+    let tx_data = TransactionData::new_native_transfer(
+        sender,
+        coin_ref,
+        SuiAddress::random_for_testing_only(),
+        1001,
+    );
+    assert_eq!(
+        state.dry_run_native_transfer(&tx_data).unwrap(),
+        Err(ExecutionFailureStatus::InsufficientCoinBalanceForTransfer {
+            available: 1000,
+            requested: 1001,
+        })
+    );
+
+    // Transactions rejected before execution are reported as errors.
+    let zero_amount = TransactionData::new_native_transfer(
+        sender,
+        coin_ref,
+        SuiAddress::random_for_testing_only(),
+        0,
+    );
+    assert!(state.dry_run_native_transfer(&zero_amount).is_err());
+}
+
+#[tokio::test]
+async fn test_execute_native_transfers_stream() {
+    let (sender, sender_key) = get_account_key_pair();
+    let (builder, coin_refs) = TestAuthorityBuilder::new()
+        .with_protocol_config(protocol_config_v2())
+        .with_gas_coins(sender, &[1000, 100, 1000]);
+    let state = builder.build().await;
+
+    // The second coin cannot cover its transfer.
+    let certs: Vec<_> = coin_refs
+        .iter()
+        .map(|coin_ref| {
+            VerifiedExecutableTransaction::new_from_quorum_execution(
+                make_native_transfer_tx(
+                    &sender_key,
+                    *coin_ref,
+                    SuiAddress::random_for_testing_only(),
+                    500,
+                ),
+                0,
+            )
+        })
+        .collect();
+    let digests: Vec<_> = certs.iter().map(|cert| *cert.digest()).collect();
+
+    let results: Vec<_> = state
+        .execute_native_transfers_stream(futures::stream::iter(certs))
+        .collect()
+        .await;
+    assert_eq!(
+        results
+            .iter()
+            .map(|(digest, _)| *digest)
+            .collect::<Vec<_>>(),
+        digests
+    );
+    assert!(results[0].1.status().is_ok());
+    assert!(
+        matches!(
+            results[1].1.status(),
+            ExecutionStatus::Failure {
+                error: ExecutionFailureStatus::InsufficientCoinBalanceForTransfer {
+                    available: 100,
+                    requested: 500,
+                },
+                ..
+            }
+        ),
+        "got: {:?}",
+        results[1].1.status()
+    );
+    assert!(results[2].1.status().is_ok());
+}
+
+#[tokio::test]
+async fn test_native_transfer_metrics() {
+    let (sender, sender_key) = get_account_key_pair();
+    let (builder, coin_refs) = TestAuthorityBuilder::new()
+        .with_protocol_config(protocol_config_v2())
+        .with_gas_coins(sender, &[1000, 100]);
+    let state = builder.build().await;
+
+    // The first coin covers its transfer, the second does not.
+    for coin_ref in coin_refs {
+        let tx_data = TransactionData::new_native_transfer(
+            sender,
+            coin_ref,
+            SuiAddress::random_for_testing_only(),
+            500,
+        );
+        send_and_confirm_transaction(
+            &state,
+            None,
+            to_sender_signed_transaction(tx_data, &sender_key),
+        )
+        .await
+        .unwrap();
+    }
+
+    let metrics = &state.metrics;
+    assert_eq!(metrics.native_transfers_total.get(), 2);
+    assert_eq!(
+        metrics
+            .native_transfers_failed_total
+            .with_label_values(&["InsufficientCoinBalanceForTransfer"])
+            .get(),
+        1
+    );
+    assert_eq!(metrics.native_transfer_amount.get_sample_count(), 1);
+    assert_eq!(metrics.native_transfer_amount.get_sample_sum(), 500.0);
+}
+
+// Fail points only fire in simulation builds.
+#[cfg(msim)]
+mod sim_only_tests {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use sui_macros::{register_fail_point, sim_test};
+    use sui_test_transaction_builder::TestTransactionBuilder;
+
+    use super::*;
+
+    #[sim_test]
+    async fn test_native_transfer_skips_move_vm_and_gas_meter() {
+        let (sender, sender_key) = get_account_key_pair();
+        let recipient = SuiAddress::random_for_testing_only();
+
+        let gas_coin = GasCoin::new(ObjectID::random(), 1000);
+        let coin_object = Object::new_move(
+            gas_coin.to_object(sui_types::base_types::SequenceNumber::from_u64(1)),
+            sui_types::object::Owner::AddressOwner(sender),
+            TransactionDigest::ZERO,
+        );
+        let coin_ref = coin_object.compute_object_reference();
+        let gas_object = Object::with_id_owner_for_testing(ObjectID::random(), sender);
+        let gas_ref = gas_object.compute_object_reference();
+
+        let state = TestAuthorityBuilder::new()
+            .with_protocol_config(protocol_config_v2())
+            .with_starting_objects(&[coin_object, gas_object])
+            .build()
+            .await;
+
+        let execution_contexts = Arc::new(AtomicUsize::new(0));
+        let metered_gas_chargers = Arc::new(AtomicUsize::new(0));
+        register_fail_point("v2-execution-context-new", {
+            let counter = execution_contexts.clone();
+            move || {
+                counter.fetch_add(1, Ordering::Relaxed);
+            }
+        });
+        register_fail_point("v2-metered-gas-charger-new", {
+            let counter = metered_gas_chargers.clone();
+            move || {
+                counter.fetch_add(1, Ordering::Relaxed);
+            }
+        });
+
+        let tx_data = TransactionData::new_native_transfer(sender, coin_ref, recipient, 500);
+        let signed_tx = to_sender_signed_transaction(tx_data, &sender_key);
+        let (_cert, effects) = send_and_confirm_transaction(&state, None, signed_tx)
+            .await
+            .unwrap();
+        assert!(effects.status().is_ok());
+        assert_eq!(execution_contexts.load(Ordering::Relaxed), 0);
+        assert_eq!(metered_gas_chargers.load(Ordering::Relaxed), 0);
+
+        // The same transfer as a programmable transaction goes through both, so the hooks are live.
+        let tx_data = TestTransactionBuilder::new(
+            sender,
+            gas_ref,
+            state.reference_gas_price_for_testing().unwrap(),
+        )
+        .transfer_sui(Some(500), recipient)
+        .build();
+        let signed_tx = to_sender_signed_transaction(tx_data, &sender_key);
+        send_and_confirm_transaction(&state, None, signed_tx)
+            .await
+            .unwrap();
+        assert!(execution_contexts.load(Ordering::Relaxed) > 0);
+        assert!(metered_gas_chargers.load(Ordering::Relaxed) > 0);
+    }
+}
+
+/// Sends a native transfer of `amount` from a new coin of the sender into a new coin of the
+/// recipient, returning the state, the result and the two coin IDs.
+async fn send_transfer_into(
+    sender_balance: u64,
+    recipient_balance: u64,
+    amount: u64,
+    sign_as_recipient: bool,
+) -> (
+    Arc<AuthorityState>,
+    SuiResult<SignedTransactionEffects>,
+    ObjectID,
+    ObjectID,
+) {
+    let (sender, sender_key) = get_account_key_pair();
+    let (recipient, recipient_key) = get_account_key_pair();
+    let (builder, sender_coins) = TestAuthorityBuilder::new()
+        .with_protocol_config(protocol_config_v2())
+        .with_gas_coins(sender, &[sender_balance]);
+    let (builder, recipient_coins) = builder.with_gas_coins(recipient, &[recipient_balance]);
+    let state = builder.build().await;
+
+    let tx_data = TransactionData::new_native_transfer_into(
+        sender,
+        sender_coins[0],
+        recipient,
+        recipient_coins[0],
+        amount,
+    );
+    let mut signers: Vec<&dyn Signer<Signature>> = vec![&sender_key];
+    if sign_as_recipient {
+        signers.push(&recipient_key);
+    }
+    let signed_tx = to_sender_signed_transaction_with_multi_signers(tx_data, signers);
+    let result = send_and_confirm_transaction(&state, None, signed_tx)
+        .await
+        .map(|(_cert, effects)| effects);
+    (state, result, sender_coins[0].0, recipient_coins[0].0)
+}
+
+#[tokio::test]
+async fn test_native_transfer_into() {
+    let (state, result, coin_id, recipient_coin_id) =
+        send_transfer_into(1000, 250, 300, true).await;
+    let effects = result.unwrap();
+    assert!(effects.status().is_ok(), "{:?}", effects.status());
+    assert!(effects.created().is_empty());
+    let mutated: BTreeSet<_> = effects.mutated().iter().map(|(r, _)| r.0).collect();
+    assert_eq!(mutated, BTreeSet::from([coin_id, recipient_coin_id]));
+
+    let coin = state.get_object(&coin_id).await.unwrap();
+    assert_eq!(GasCoin::try_from(&coin).unwrap().value(), 700);
+    let recipient_coin = state.get_object(&recipient_coin_id).await.unwrap();
+    assert_eq!(GasCoin::try_from(&recipient_coin).unwrap().value(), 550);
+}
+
+#[tokio::test]
+async fn test_native_transfer_into_recipient_balance_overflow() {
+    let (state, result, coin_id, recipient_coin_id) =
+        send_transfer_into(1000, u64::MAX - 10, 300, true).await;
+    let effects = result.unwrap();
+    assert!(
+        matches!(
+            effects.status(),
+            ExecutionStatus::Failure {
+                error: ExecutionFailureStatus::CoinBalanceOverflow,
+                ..
+            }
+        ),
+        "Should fail with CoinBalanceOverflow, got: {:?}",
+        effects.status()
+    );
+
+    let coin = state.get_object(&coin_id).await.unwrap();
+    assert_eq!(GasCoin::try_from(&coin).unwrap().value(), 1000);
+    let recipient_coin = state.get_object(&recipient_coin_id).await.unwrap();
+    assert_eq!(
+        GasCoin::try_from(&recipient_coin).unwrap().value(),
+        u64::MAX - 10
+    );
+}
+
+#[tokio::test]
+async fn test_native_transfer_into_requires_recipient_signature() {
+    let (_state, result, _, _) = send_transfer_into(1000, 250, 300, false).await;
+    let err = result.unwrap_err();
+    assert!(
+        matches!(
+            err.as_inner(),
+            SuiErrorKind::SignerSignatureNumberMismatch {
+                expected: 2,
+                actual: 1
+            }
+        ),
+        "Should require the recipient's signature, got: {:?}",
+        err
+    );
+}
+
+#[tokio::test]
+async fn test_native_transfer_into_coin_not_owned_by_recipient() {
+    let (sender, sender_key) = get_account_key_pair();
+    let (recipient, recipient_key) = get_account_key_pair();
+    let (builder, sender_coins) = TestAuthorityBuilder::new()
+        .with_protocol_config(protocol_config_v2())
+        .with_gas_coins(sender, &[1000, 250]);
+    let state = builder.build().await;
+
+    // The recipient signs, but the coin to credit belongs to the sender.
+    let tx_data = TransactionData::new_native_transfer_into(
+        sender,
+        sender_coins[0],
+        recipient,
+        sender_coins[1],
+        300,
+    );
+    let signed_tx =
+        to_sender_signed_transaction_with_multi_signers(tx_data, vec![&sender_key, &recipient_key]);
+    let err = send_and_confirm_transaction(&state, None, signed_tx)
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(
+            err.as_inner(),
+            SuiErrorKind::UserInputError {
+                error: UserInputError::IncorrectUserSignature { .. }
+            }
+        ),
+        "Should reject a coin the recipient does not own, got: {:?}",
+        err
+    );
+}
@@ -0,0 +1,289 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! End-to-end coverage for `TransactionKind::NativeTransfer`.
+//!
+//! The transfer is built and signed with the wallet, submitted through the fullnode's
+//! transaction orchestrator, and then read back through the JSON-RPC read API and the fullnode
+//! store, checking that amount and recipient agree at every layer.
+//!
+//! It also checks that signing conflicting transfers of one coin version with different
+//! validators is detected, and leaves the coin locked for the epoch.
+//!
+//! GraphQL (`sui-graphql-rpc` and `sui-indexer-alt-graphql`) does not support native transfers
+//! yet, so neither the indexer nor GraphQL is exercised here.
+
+use sui_json_rpc_api::TransactionBuilderClient;
+use sui_json_rpc_types::{
+    SuiObjectDataOptions, SuiTransactionBlockDataAPI, SuiTransactionBlockEffectsAPI,
+    SuiTransactionBlockKind, SuiTransactionBlockResponseOptions,
+};
+use sui_macros::sim_test;
+use sui_protocol_config::{
+    ExecutionTimeEstimateParams, OverrideGuard, PerObjectCongestionControlMode, ProtocolConfig,
+};
+use sui_types::base_types::SuiAddress;
+use sui_types::error::SuiErrorKind;
+use sui_types::gas_coin::GasCoin;
+use sui_types::object::Owner;
+use sui_types::supported_protocol_versions::SupportedProtocolVersions;
+use sui_types::transaction::{TransactionData, VerifiedTransaction};
+use test_cluster::{TestCluster, TestClusterBuilder};
+
+/// Native transfers are only executed by the v2 execution layer, which protocol version 31 uses.
+const NATIVE_TRANSFER_PROTOCOL_VERSION: u64 = 31;
+
+fn native_transfer_protocol_overrides() -> OverrideGuard {
+    ProtocolConfig::apply_overrides_for_testing(|_, mut config| {
+        // The new consensus handler requires these flags, and they are irrelevant to the test
+        config.set_ignore_execution_time_observations_after_certs_closed_for_testing(true);
+        config.set_record_time_estimate_processed_for_testing(true);
+        config.set_prepend_prologue_tx_in_consensus_commit_in_checkpoints_for_testing(true);
+        config.set_consensus_checkpoint_signature_key_includes_digest_for_testing(true);
+        config.set_cancel_for_failed_dkg_early_for_testing(true);
+        config.set_use_mfp_txns_in_load_initial_object_debts_for_testing(true);
+        config.set_authority_capabilities_v2_for_testing(true);
+        config.set_native_transfer_enabled_for_testing(true);
+        config.set_per_object_congestion_control_mode_for_testing(
+            PerObjectCongestionControlMode::ExecutionTimeEstimate(ExecutionTimeEstimateParams {
+                target_utilization: 50,
+                allowed_txn_cost_overage_burst_limit_us: 500_000,
+                randomness_scalar: 20,
+                max_estimate_us: 1_500_000,
+                stored_observations_num_included_checkpoints: 10,
+                stored_observations_limit: 180,
+                stake_weighted_median_threshold: 3334,
+                default_none_duration_for_new_keys: true,
+                observations_chunk_size: Some(18),
+            }),
+        );
+        config
+    })
+}
+
+async fn build_native_transfer_cluster() -> TestCluster {
+    let framework =
+        sui_framework_snapshot::load_bytecode_snapshot(NATIVE_TRANSFER_PROTOCOL_VERSION)
+            .unwrap()
+            .into_iter()
+            .map(|p| p.genesis_object())
+            .collect::<Vec<_>>();
+
+    TestClusterBuilder::new()
+        .with_protocol_version(NATIVE_TRANSFER_PROTOCOL_VERSION.into())
+        .with_supported_protocol_versions(SupportedProtocolVersions::new_for_testing(
+            NATIVE_TRANSFER_PROTOCOL_VERSION,
+            NATIVE_TRANSFER_PROTOCOL_VERSION,
+        ))
+        .with_objects(framework)
+        .build()
+        .await
+}
+
+#[sim_test]
+async fn test_native_transfer_end_to_end() {
+    let _guard = native_transfer_protocol_overrides();
+    let test_cluster = build_native_transfer_cluster().await;
+
+    let sender = test_cluster.get_address_0();
+    let recipient = SuiAddress::random_for_testing_only();
+    let coin_ref = test_cluster
+        .wallet
+        .get_one_gas_object_owned_by_address(sender)
+        .await
+        .unwrap()
+        .unwrap();
+    let coin_value = GasCoin::try_from(
+        &test_cluster
+            .get_object_from_fullnode_store(&coin_ref.0)
+            .await
+            .unwrap(),
+    )
+    .unwrap()
+    .value();
+    let amount = coin_value / 4;
+
+    // Build and sign with the wallet, then submit through the fullnode orchestrator.
+    let tx_data = TransactionData::new_native_transfer(sender, coin_ref, recipient, amount);
+    let response = test_cluster.sign_and_execute_transaction(&tx_data).await;
+    let digest = response.digest;
+
+    // The orchestrator response carries the transaction as submitted.
+    let SuiTransactionBlockKind::NativeTransfer(transfer) =
+        response.transaction.as_ref().unwrap().data.transaction()
+    else {
+        panic!("expected a native transfer in the executed transaction");
+    };
+    assert_eq!(transfer.recipient, recipient);
+    assert_eq!(transfer.amount, amount);
+    assert_eq!(transfer.coin.object_id, coin_ref.0);
+
+    let effects = response.effects.as_ref().unwrap();
+    assert_eq!(effects.gas_cost_summary().net_gas_usage(), 0);
+    assert_eq!(effects.created().len(), 1);
+    let created = &effects.created()[0];
+    assert_eq!(created.owner, Owner::AddressOwner(recipient));
+
+    let balance_changes = response.balance_changes.as_ref().unwrap();
+    let change_for = |owner: SuiAddress| {
+        balance_changes
+            .iter()
+            .find(|change| change.owner == Owner::AddressOwner(owner))
+            .map(|change| change.amount)
+    };
+    assert_eq!(change_for(sender), Some(-(amount as i128)));
+    assert_eq!(change_for(recipient), Some(amount as i128));
+
+    // Once checkpointed, the read API must report the same transfer.
+    test_cluster.wait_for_tx_settlement(&[digest]).await;
+    let client = test_cluster.wallet.get_client().await.unwrap();
+    let queried = client
+        .read_api()
+        .get_transaction_with_options(
+            digest,
+            SuiTransactionBlockResponseOptions::new()
+                .with_input()
+                .with_effects(),
+        )
+        .await
+        .unwrap();
+    assert!(queried.checkpoint.is_some());
+    let SuiTransactionBlockKind::NativeTransfer(queried_transfer) =
+        queried.transaction.as_ref().unwrap().data.transaction()
+    else {
+        panic!("expected a native transfer from the read API");
+    };
+    assert_eq!(queried_transfer, transfer);
+    assert_eq!(
+        queried.effects.as_ref().unwrap().created()[0].reference,
+        created.reference
+    );
+
+    let created_coin = client
+        .read_api()
+        .get_object_with_options(
+            created.reference.object_id,
+            SuiObjectDataOptions::new().with_owner().with_bcs(),
+        )
+        .await
+        .unwrap()
+        .into_object()
+        .unwrap();
+    assert_eq!(created_coin.owner, Some(Owner::AddressOwner(recipient)));
+
+    // The fullnode store agrees on the resulting balances.
+    let source = test_cluster
+        .get_object_from_fullnode_store(&coin_ref.0)
+        .await
+        .unwrap();
+    assert_eq!(
+        GasCoin::try_from(&source).unwrap().value(),
+        coin_value - amount
+    );
+    let received = test_cluster
+        .get_object_from_fullnode_store(&created.reference.object_id)
+        .await
+        .unwrap();
+    assert_eq!(GasCoin::try_from(&received).unwrap().value(), amount);
+}
+
+#[sim_test]
+async fn test_native_transfer_equivocation_locks_coin() {
+    let _guard = native_transfer_protocol_overrides();
+    let test_cluster = build_native_transfer_cluster().await;
+
+    let sender = test_cluster.get_address_0();
+    let coin_ref = test_cluster
+        .wallet
+        .get_one_gas_object_owned_by_address(sender)
+        .await
+        .unwrap()
+        .unwrap();
+    let wallet = &test_cluster.wallet;
+    let sign_transfer = |amount| {
+        let tx_data = TransactionData::new_native_transfer(
+            sender,
+            coin_ref,
+            SuiAddress::random_for_testing_only(),
+            amount,
+        );
+        async move { wallet.sign_transaction(&tx_data).await }
+    };
+
+    // Equivocate by sending each half of the committee a different transfer of the same coin
+    // version, so neither can gather a quorum of signatures.
+    let t1 = sign_transfer(1).await;
+    let t2 = sign_transfer(2).await;
+    let validators = test_cluster.all_validator_handles();
+    for (idx, validator) in validators.iter().enumerate() {
+        let t = if idx % 2 == 0 { t1.clone() } else { t2.clone() };
+        let state = validator.state();
+        let epoch_store = state.epoch_store_for_testing();
+        state
+            .handle_transaction(&epoch_store, VerifiedTransaction::new_unchecked(t))
+            .await
+            .unwrap();
+    }
+
+    // Each validator detects the conflicting transfer and names the one holding its lock.
+    let state = validators[0].state();
+    let epoch_store = state.epoch_store_for_testing();
+    let err = state
+        .handle_transaction(&epoch_store, VerifiedTransaction::new_unchecked(t2.clone()))
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.into_inner(),
+        SuiErrorKind::ObjectLockConflict {
+            obj_ref: coin_ref,
+            pending_transaction: *t1.digest(),
+        }
+    );
+
+    // Neither transfer can be certified, and the coin version stays locked for the rest of the
+    // epoch, so a new transfer of it cannot be certified either.
+    test_cluster.create_certificate(t1, None).await.unwrap_err();
+    test_cluster.create_certificate(t2, None).await.unwrap_err();
+    let t3 = sign_transfer(3).await;
+    test_cluster.create_certificate(t3, None).await.unwrap_err();
+
+    let coin = test_cluster
+        .get_object_from_fullnode_store(&coin_ref.0)
+        .await
+        .unwrap();
+    assert_eq!(coin.compute_object_reference(), coin_ref);
+}
+
+#[sim_test]
+async fn test_native_transfer_built_over_json_rpc() {
+    let _guard = native_transfer_protocol_overrides();
+    let test_cluster = build_native_transfer_cluster().await;
+
+    let sender = test_cluster.get_address_0();
+    let recipient = SuiAddress::random_for_testing_only();
+    let coin_ref = test_cluster
+        .wallet
+        .get_one_gas_object_owned_by_address(sender)
+        .await
+        .unwrap()
+        .unwrap();
+
+    // The node resolves the coin's latest version and reports the coin the transfer creates.
+    let bytes = test_cluster
+        .rpc_client()
+        .native_transfer(sender, coin_ref.0, recipient, 1_000.into())
+        .await
+        .unwrap();
+    let created_coin_id = bytes.created_coin_id;
+    let tx_data = bytes.to_data().unwrap();
+    assert_eq!(
+        tx_data,
+        TransactionData::new_native_transfer(sender, coin_ref, recipient, 1_000)
+    );
+
+    let response = test_cluster.sign_and_execute_transaction(&tx_data).await;
+    let created = response.effects.as_ref().unwrap().created();
+    assert_eq!(created.len(), 1);
+    assert_eq!(created[0].reference.object_id, created_coin_id);
+    assert_eq!(created[0].owner, Owner::AddressOwner(recipient));
+}
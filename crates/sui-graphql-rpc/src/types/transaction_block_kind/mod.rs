@@ -88,6 +88,36 @@ impl TransactionBlockKind {
             K::NativeTransfer(_) => {
                 panic!("NativeTransfer is not supported in GraphQL API")
             }
+            K::NativeTopUp(_) => {
+                panic!("NativeTopUp is not supported in GraphQL API")
+            }
+            K::NativeMultiTransfer(_) => {
+                panic!("NativeMultiTransfer is not supported in GraphQL API")
+            }
+            K::NativeMerge(_) => {
+                panic!("NativeMerge is not supported in GraphQL API")
+            }
+            K::NativeTransferWhole(_) => {
+                panic!("NativeTransferWhole is not supported in GraphQL API")
+            }
+            K::NativeTransferWithChange(_) => {
+                panic!("NativeTransferWithChange is not supported in GraphQL API")
+            }
+            K::NativeSweep(_) => {
+                panic!("NativeSweep is not supported in GraphQL API")
+            }
+            K::NativeTransferAfterEpoch(_) => {
+                panic!("NativeTransferAfterEpoch is not supported in GraphQL API")
+            }
+            K::NativeSplitEqual(_) => {
+                panic!("NativeSplitEqual is not supported in GraphQL API")
+            }
+            K::NativeTransferInto(_) => {
+                panic!("NativeTransferInto is not supported in GraphQL API")
+            }
+            K::NativeTransferWithConsent(_) => {
+                panic!("NativeTransferWithConsent is not supported in GraphQL API")
+            }
         }
     }
 }
@@ -80,8 +80,18 @@ impl TransactionKind {
                     inner: ProgrammableTransaction { native: pt, scope },
                 }))
             }
-            K::NativeTransfer(_) => {
-                // NativeTransfer is not supported in GraphQL API
+            K::NativeTransfer(_)
+            | K::NativeTopUp(_)
+            | K::NativeMultiTransfer(_)
+            | K::NativeMerge(_)
+            | K::NativeTransferWhole(_)
+            | K::NativeTransferWithChange(_)
+            | K::NativeSweep(_)
+            | K::NativeTransferAfterEpoch(_)
+            | K::NativeSplitEqual(_)
+            | K::NativeTransferInto(_)
+            | K::NativeTransferWithConsent(_) => {
+                // Native transactions are not supported in GraphQL API
                 None
             }
         }
@@ -7,7 +7,8 @@ use jsonrpsee::proc_macros::rpc;
 
 use sui_json::SuiJsonValue;
 use sui_json_rpc_types::{
-    RPCTransactionRequestParams, SuiTransactionBlockBuilderMode, SuiTypeTag, TransactionBlockBytes,
+    NativeTransferBytes, RPCTransactionRequestParams, SuiTransactionBlockBuilderMode, SuiTypeTag,
+    TransactionBlockBytes,
 };
 use sui_open_rpc_macros::open_rpc;
 use sui_types::base_types::{ObjectID, SuiAddress};
@@ -49,6 +50,22 @@ pub trait TransactionBuilder {
         amount: Option<BigInt<u64>>,
     ) -> RpcResult<TransactionBlockBytes>;
 
+    /// Create an unsigned native transfer that splits `amount` off a SUI coin and sends it to a Sui address.
+    /// Native transfers bypass the Move VM and are not charged gas. The response includes the ID of the coin
+    /// the transfer creates for the recipient.
+    #[method(name = "nativeTransfer")]
+    async fn native_transfer(
+        &self,
+        /// the transaction signer's Sui address
+        signer: SuiAddress,
+        /// the Sui coin object to split the amount from, the node uses its latest version
+        coin_object_id: ObjectID,
+        /// the recipient's Sui address
+        recipient: SuiAddress,
+        /// the amount to be split out and transferred
+        amount: BigInt<u64>,
+    ) -> RpcResult<NativeTransferBytes>;
+
     /// Send `Coin<T>` to a list of addresses, where `T` can be any coin type, following a list of amounts,
     /// The object specified in the `gas` field will be used to pay the gas fee for the transaction.
     /// The gas object can not appear in `input_coins`. If the gas object is not specified, the RPC server
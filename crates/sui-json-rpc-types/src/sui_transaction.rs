@@ -427,6 +427,30 @@ pub enum SuiTransactionBlockKind {
     ProgrammableSystemTransaction(SuiProgrammableTransactionBlock),
     /// A native transfer transaction that bypasses Move VM and doesn't charge gas
     NativeTransfer(SuiNativeTransfer),
+    /// A native top-up of one coin from another that bypasses Move VM and doesn't charge gas
+    NativeTopUp(SuiNativeTopUp),
+    /// A native transfer from one coin to several recipients that bypasses Move VM and doesn't
+    /// charge gas
+    NativeMultiTransfer(SuiNativeMultiTransfer),
+    /// A native merge of several coins into one that bypasses Move VM and doesn't charge gas
+    NativeMerge(SuiNativeMerge),
+    /// A native transfer of a whole coin that bypasses Move VM and doesn't charge gas
+    NativeTransferWhole(SuiNativeTransferWhole),
+    /// A native transfer that sends the change to a third party, bypassing Move VM without
+    /// charging gas
+    NativeTransferWithChange(SuiNativeTransferWithChange),
+    /// A native transfer of several whole coins that bypasses Move VM and doesn't charge gas
+    NativeSweep(SuiNativeSweep),
+    /// A native transfer that cannot execute before a given epoch, bypassing Move VM without
+    /// charging gas
+    NativeTransferAfterEpoch(SuiNativeTransferAfterEpoch),
+    /// A native split of a coin into equal parts that bypasses Move VM and doesn't charge gas
+    NativeSplitEqual(SuiNativeSplitEqual),
+    /// A native transfer into a coin the recipient already owns that bypasses Move VM and
+    /// doesn't charge gas
+    NativeTransferInto(SuiNativeTransferInto),
+    /// A native transfer that the recipient must co-sign, bypassing Move VM without charging gas
+    NativeTransferWithConsent(SuiNativeTransfer),
     // .. more transaction types go here
 }
 
@@ -506,6 +530,36 @@ impl Display for SuiTransactionBlockKind {
             Self::NativeTransfer(_) => {
                 writeln!(writer, "Transaction Kind: Native Transfer")?;
             }
+            Self::NativeTopUp(_) => {
+                writeln!(writer, "Transaction Kind: Native Top-Up")?;
+            }
+            Self::NativeMultiTransfer(_) => {
+                writeln!(writer, "Transaction Kind: Native Multi-Recipient Transfer")?;
+            }
+            Self::NativeMerge(_) => {
+                writeln!(writer, "Transaction Kind: Native Merge")?;
+            }
+            Self::NativeTransferWhole(_) => {
+                writeln!(writer, "Transaction Kind: Native Whole-Coin Transfer")?;
+            }
+            Self::NativeTransferWithChange(_) => {
+                writeln!(writer, "Transaction Kind: Native Transfer With Change")?;
+            }
+            Self::NativeSweep(_) => {
+                writeln!(writer, "Transaction Kind: Native Sweep")?;
+            }
+            Self::NativeTransferAfterEpoch(_) => {
+                writeln!(writer, "Transaction Kind: Native Transfer After Epoch")?;
+            }
+            Self::NativeSplitEqual(_) => {
+                writeln!(writer, "Transaction Kind: Native Split Equal")?;
+            }
+            Self::NativeTransferInto(_) => {
+                writeln!(writer, "Transaction Kind: Native Transfer Into")?;
+            }
+            Self::NativeTransferWithConsent(_) => {
+                writeln!(writer, "Transaction Kind: Native Transfer With Consent")?;
+            }
         }
         write!(f, "{}", writer)
     }
@@ -566,6 +620,69 @@ impl SuiTransactionBlockKind {
                 recipient: transfer.recipient,
                 amount: transfer.amount,
             }),
+            TransactionKind::NativeTopUp(top_up) => Self::NativeTopUp(SuiNativeTopUp {
+                source: top_up.source.into(),
+                target: top_up.target.into(),
+                target_balance: top_up.target_balance,
+            }),
+            TransactionKind::NativeMultiTransfer(transfer) => {
+                Self::NativeMultiTransfer(SuiNativeMultiTransfer {
+                    coin: transfer.coin.into(),
+                    recipients: transfer.recipients,
+                })
+            }
+            TransactionKind::NativeMerge(merge) => Self::NativeMerge(SuiNativeMerge {
+                primary_coin: merge.primary_coin.into(),
+                coins_to_merge: merge.coins_to_merge.into_iter().map(Into::into).collect(),
+            }),
+            TransactionKind::NativeTransferWhole(transfer) => {
+                Self::NativeTransferWhole(SuiNativeTransferWhole {
+                    coin: transfer.coin.into(),
+                    recipient: transfer.recipient,
+                })
+            }
+            TransactionKind::NativeTransferWithChange(transfer) => {
+                Self::NativeTransferWithChange(SuiNativeTransferWithChange {
+                    coin: transfer.coin.into(),
+                    recipient: transfer.recipient,
+                    amount: transfer.amount,
+                    change_recipient: transfer.change_recipient,
+                })
+            }
+            TransactionKind::NativeSweep(sweep) => Self::NativeSweep(SuiNativeSweep {
+                coins: sweep.coins.into_iter().map(Into::into).collect(),
+                recipient: sweep.recipient,
+            }),
+            TransactionKind::NativeTransferAfterEpoch(transfer) => {
+                Self::NativeTransferAfterEpoch(SuiNativeTransferAfterEpoch {
+                    coin: transfer.coin.into(),
+                    recipient: transfer.recipient,
+                    amount: transfer.amount,
+                    not_before_epoch: transfer.not_before_epoch,
+                })
+            }
+            TransactionKind::NativeSplitEqual(split) => {
+                Self::NativeSplitEqual(SuiNativeSplitEqual {
+                    coin: split.coin.into(),
+                    parts: split.parts,
+                    recipient: split.recipient,
+                })
+            }
+            TransactionKind::NativeTransferInto(transfer) => {
+                Self::NativeTransferInto(SuiNativeTransferInto {
+                    coin: transfer.coin.into(),
+                    recipient: transfer.recipient,
+                    recipient_coin: transfer.recipient_coin.into(),
+                    amount: transfer.amount,
+                })
+            }
+            TransactionKind::NativeTransferWithConsent(transfer) => {
+                Self::NativeTransferWithConsent(SuiNativeTransfer {
+                    coin: transfer.coin.into(),
+                    recipient: transfer.recipient,
+                    amount: transfer.amount,
+                })
+            }
             TransactionKind::AuthenticatorStateUpdate(update) => {
                 Self::AuthenticatorStateUpdate(SuiAuthenticatorStateUpdate {
                     epoch: update.epoch,
@@ -703,6 +820,16 @@ impl SuiTransactionBlockKind {
             Self::RandomnessStateUpdate(_) => "RandomnessStateUpdate",
             Self::EndOfEpochTransaction(_) => "EndOfEpochTransaction",
             Self::NativeTransfer(_) => "NativeTransfer",
+            Self::NativeTopUp(_) => "NativeTopUp",
+            Self::NativeMultiTransfer(_) => "NativeMultiTransfer",
+            Self::NativeMerge(_) => "NativeMerge",
+            Self::NativeTransferWhole(_) => "NativeTransferWhole",
+            Self::NativeTransferWithChange(_) => "NativeTransferWithChange",
+            Self::NativeSweep(_) => "NativeSweep",
+            Self::NativeTransferAfterEpoch(_) => "NativeTransferAfterEpoch",
+            Self::NativeSplitEqual(_) => "NativeSplitEqual",
+            Self::NativeTransferInto(_) => "NativeTransferInto",
+            Self::NativeTransferWithConsent(_) => "NativeTransferWithConsent",
         }
     }
 }
@@ -1521,6 +1648,10 @@ pub struct SuiTransactionBlockDataV1 {
     pub transaction: SuiTransactionBlockKind,
     pub sender: SuiAddress,
     pub gas_data: SuiGasData,
+    /// Whether the transaction is exempt from gas metering, as native transfers and top-ups are.
+    /// Omitted for metered transactions.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_unmetered: bool,
 }
 
 impl SuiTransactionBlockDataAPI for SuiTransactionBlockDataV1 {
@@ -1558,6 +1689,7 @@ impl SuiTransactionBlockData {
     ) -> Result<Self, anyhow::Error> {
         let message_version = data.message_version();
         let sender = data.sender();
+        let is_unmetered = data.kind().is_unmetered();
         let gas_data = SuiGasData {
             payment: data
                 .gas()
@@ -1574,6 +1706,7 @@ impl SuiTransactionBlockData {
                 transaction,
                 sender,
                 gas_data,
+                is_unmetered,
             })),
             _ => Err(anyhow::anyhow!(
                 "Support for TransactionData version {} not implemented",
@@ -1791,6 +1924,85 @@ pub struct SuiNativeTransfer {
     pub amount: u64,
 }
 
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct SuiNativeTopUp {
+    pub source: SuiObjectRef,
+    pub target: SuiObjectRef,
+    #[schemars(with = "BigInt<u64>")]
+    #[serde_as(as = "BigInt<u64>")]
+    pub target_balance: u64,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct SuiNativeMultiTransfer {
+    pub coin: SuiObjectRef,
+    #[schemars(with = "Vec<(SuiAddress, BigInt<u64>)>")]
+    #[serde_as(as = "Vec<(_, BigInt<u64>)>")]
+    pub recipients: Vec<(SuiAddress, u64)>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct SuiNativeMerge {
+    pub primary_coin: SuiObjectRef,
+    pub coins_to_merge: Vec<SuiObjectRef>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct SuiNativeTransferWhole {
+    pub coin: SuiObjectRef,
+    pub recipient: SuiAddress,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct SuiNativeTransferWithChange {
+    pub coin: SuiObjectRef,
+    pub recipient: SuiAddress,
+    #[schemars(with = "BigInt<u64>")]
+    #[serde_as(as = "BigInt<u64>")]
+    pub amount: u64,
+    pub change_recipient: SuiAddress,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct SuiNativeSweep {
+    pub coins: Vec<SuiObjectRef>,
+    pub recipient: SuiAddress,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct SuiNativeTransferAfterEpoch {
+    pub coin: SuiObjectRef,
+    pub recipient: SuiAddress,
+    #[schemars(with = "BigInt<u64>")]
+    #[serde_as(as = "BigInt<u64>")]
+    pub amount: u64,
+    #[schemars(with = "BigInt<u64>")]
+    #[serde_as(as = "BigInt<u64>")]
+    pub not_before_epoch: EpochId,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct SuiNativeSplitEqual {
+    pub coin: SuiObjectRef,
+    pub parts: u32,
+    pub recipient: SuiAddress,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct SuiNativeTransferInto {
+    pub coin: SuiObjectRef,
+    pub recipient: SuiAddress,
+    pub recipient_coin: SuiObjectRef,
+    #[schemars(with = "BigInt<u64>")]
+    #[serde_as(as = "BigInt<u64>")]
+    pub amount: u64,
+}
+
 #[serde_as]
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 pub struct SuiEndOfEpochTransaction {
@@ -2344,6 +2556,40 @@ impl TransactionBlockBytes {
     }
 }
 
+#[serde_as]
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct NativeTransferBytes {
+    /// BCS serialized transaction data bytes without its type tag, as base-64 encoded string.
+    pub tx_bytes: Base64,
+    /// objects to be used in this transaction
+    pub input_objects: Vec<SuiInputObjectKind>,
+    /// the ID of the coin the transfer creates for the recipient
+    pub created_coin_id: ObjectID,
+}
+
+impl NativeTransferBytes {
+    pub fn from_data(data: TransactionData) -> Result<Self, anyhow::Error> {
+        let created_coin_id = data
+            .native_transfer_created_coin_id()
+            .ok_or_else(|| anyhow::anyhow!("Transaction is not a native transfer"))?;
+        Ok(Self {
+            tx_bytes: Base64::from_bytes(bcs::to_bytes(&data)?.as_slice()),
+            input_objects: data
+                .input_objects()?
+                .into_iter()
+                .map(SuiInputObjectKind::from)
+                .collect(),
+            created_coin_id,
+        })
+    }
+
+    pub fn to_data(self) -> Result<TransactionData, anyhow::Error> {
+        bcs::from_bytes::<TransactionData>(&self.tx_bytes.to_vec().map_err(|e| anyhow::anyhow!(e))?)
+            .map_err(|e| anyhow::anyhow!(e))
+    }
+}
+
 #[derive(Eq, PartialEq, Clone, Debug, Serialize, Deserialize, JsonSchema)]
 #[serde(rename = "OwnedObjectRef")]
 pub struct OwnedObjectRef {
@@ -10,13 +10,15 @@ use move_core_types::identifier::Identifier;
 use move_core_types::language_storage::{StructTag, TypeTag};
 use serde_json::json;
 
-use sui_types::base_types::{ObjectDigest, SequenceNumber};
+use sui_types::base_types::{ObjectDigest, SequenceNumber, random_object_ref};
 use sui_types::base_types::{ObjectID, SuiAddress};
 use sui_types::gas_coin::GasCoin;
+use sui_types::in_memory_storage::InMemoryStorage;
 use sui_types::object::{MoveObject, Owner};
+use sui_types::transaction::TransactionData;
 use sui_types::{MOVE_STDLIB_ADDRESS, SUI_FRAMEWORK_ADDRESS, parse_sui_struct_tag};
 
-use crate::{ObjectChange, SuiMoveStruct, SuiMoveValue};
+use crate::{ObjectChange, SuiMoveStruct, SuiMoveValue, SuiTransactionBlockData};
 
 #[test]
 fn test_move_value_to_sui_coin() {
@@ -319,3 +321,36 @@ fn test_type_tag_struct_tag_devnet_inc_222() {
         assert_eq!(oc, deser);
     }
 }
+
+#[test]
+fn test_transaction_block_data_unmetered_marker() {
+    let sender = SuiAddress::random_for_testing_only();
+    let recipient = SuiAddress::random_for_testing_only();
+    let to_json = |data| {
+        let data =
+            SuiTransactionBlockData::try_from_with_module_cache(data, &InMemoryStorage::default())
+                .unwrap();
+        serde_json::to_value(data).unwrap()
+    };
+
+    let native = to_json(TransactionData::new_native_transfer(
+        sender,
+        random_object_ref(),
+        recipient,
+        100,
+    ));
+    assert_eq!(native["isUnmetered"], json!(true));
+
+    let metered = to_json(TransactionData::new_transfer_sui(
+        recipient,
+        sender,
+        Some(100),
+        random_object_ref(),
+        1_000_000,
+        1_000,
+    ));
+    assert!(metered.get("isUnmetered").is_none());
+    let metered: SuiTransactionBlockData = serde_json::from_value(metered).unwrap();
+    let SuiTransactionBlockData::V1(metered) = metered;
+    assert!(!metered.is_unmetered);
+}
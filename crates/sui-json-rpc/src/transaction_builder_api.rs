@@ -12,11 +12,11 @@ use move_core_types::language_storage::StructTag;
 use sui_core::authority::AuthorityState;
 use sui_json::SuiJsonValue;
 use sui_json_rpc_api::{TransactionBuilderOpenRpc, TransactionBuilderServer};
-use sui_json_rpc_types::{RPCTransactionRequestParams, SuiObjectDataFilter};
 use sui_json_rpc_types::{
-    SuiObjectDataOptions, SuiObjectResponse, SuiTransactionBlockBuilderMode, SuiTypeTag,
-    TransactionBlockBytes,
+    NativeTransferBytes, SuiObjectDataOptions, SuiObjectResponse, SuiTransactionBlockBuilderMode,
+    SuiTypeTag, TransactionBlockBytes,
 };
+use sui_json_rpc_types::{RPCTransactionRequestParams, SuiObjectDataFilter};
 use sui_open_rpc::Module;
 use sui_transaction_builder::{DataReader, TransactionBuilder};
 use sui_types::base_types::ObjectInfo;
@@ -119,6 +119,21 @@ impl TransactionBuilderServer for TransactionBuilderApi {
         Ok(TransactionBlockBytes::from_data(data).map_err(crate::Error::from)?)
     }
 
+    async fn native_transfer(
+        &self,
+        signer: SuiAddress,
+        coin_object_id: ObjectID,
+        recipient: SuiAddress,
+        amount: BigInt<u64>,
+    ) -> RpcResult<NativeTransferBytes> {
+        let data = self
+            .0
+            .native_transfer(signer, coin_object_id, recipient, *amount)
+            .await
+            .map_err(crate::Error::from)?;
+        Ok(NativeTransferBytes::from_data(data).map_err(crate::Error::from)?)
+    }
+
     async fn pay(
         &self,
         signer: SuiAddress,
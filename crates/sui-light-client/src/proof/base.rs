@@ -3,8 +3,9 @@
 
 use serde::{Deserialize, Serialize};
 use sui_types::{
-    base_types::{ObjectID, ObjectRef},
+    base_types::{ObjectID, ObjectRef, SuiAddress},
     committee::Committee,
+    digests::TransactionDigest,
     event::{Event, EventID},
     full_checkpoint_content::CheckpointData,
     messages_checkpoint::{CertifiedCheckpointSummary, VerifiedCheckpoint},
@@ -15,6 +16,7 @@ use crate::proof::{
     committee::{CommitteeProof, CommitteeTarget},
     error::{ProofError, ProofResult},
     events::EventsTarget,
+    native_transfer::NativeTransferTarget,
     objects::ObjectsTarget,
     ocs::{OCSProof, OCSTarget},
     transaction_proof::TransactionProof,
@@ -38,6 +40,7 @@ pub enum ProofTarget {
     Events(EventsTarget),
     Committee(CommitteeTarget),
     ObjectCheckpointState(OCSTarget),
+    NativeTransfer(NativeTransferTarget),
 }
 
 impl ProofTarget {
@@ -60,6 +63,18 @@ impl ProofTarget {
     pub fn new_ocs_non_inclusion(object_id: ObjectID) -> Self {
         ProofTarget::ObjectCheckpointState(OCSTarget::new_non_inclusion_target(object_id))
     }
+
+    pub fn new_native_transfer(
+        transaction_digest: TransactionDigest,
+        recipient: SuiAddress,
+        amount: u64,
+    ) -> Self {
+        ProofTarget::NativeTransfer(NativeTransferTarget {
+            transaction_digest,
+            recipient,
+            amount,
+        })
+    }
 }
 
 impl ProofBuilder for ProofTarget {
@@ -69,6 +84,7 @@ impl ProofBuilder for ProofTarget {
             ProofTarget::Events(target) => target.construct(checkpoint),
             ProofTarget::Committee(target) => target.construct(checkpoint),
             ProofTarget::ObjectCheckpointState(target) => target.construct(checkpoint),
+            ProofTarget::NativeTransfer(target) => target.construct(checkpoint),
         }
     }
 }
@@ -111,7 +127,7 @@ impl ProofVerifier for Proof {
 
         // Sanity check that targets & proof types match
         match &self.targets {
-            ProofTarget::Objects(_) | ProofTarget::Events(_) => {
+            ProofTarget::Objects(_) | ProofTarget::Events(_) | ProofTarget::NativeTransfer(_) => {
                 if !matches!(self.proof_contents, ProofContents::TransactionProof(_)) {
                     return Err(ProofError::MismatchedTargetAndProofType);
                 }
@@ -44,6 +44,15 @@ pub enum ProofError {
     #[error("Events are missing from the transaction")]
     EventsMissing,
 
+    #[error("Transaction is not a native transfer")]
+    NotANativeTransfer,
+
+    #[error("Native transfer recipient or amount does not match")]
+    NativeTransferMismatch,
+
+    #[error("Transaction did not execute successfully")]
+    TransactionFailed,
+
     #[error("Contents digest does not match the checkpoint summary")]
     ContentsDigestMismatch,
 
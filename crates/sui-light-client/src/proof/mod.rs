@@ -6,6 +6,7 @@ pub mod base;
 // Targets
 pub mod committee;
 pub mod events;
+pub mod native_transfer;
 pub mod objects;
 pub mod ocs;
 
@@ -0,0 +1,33 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+use sui_types::{
+    base_types::SuiAddress, digests::TransactionDigest, full_checkpoint_content::CheckpointData,
+};
+
+use crate::proof::{
+    base::{Proof, ProofBuilder, ProofContents, ProofTarget},
+    error::ProofResult,
+    transaction_proof::TransactionProof,
+};
+
+/// A successful native transfer of `amount` MIST to `recipient`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct NativeTransferTarget {
+    pub transaction_digest: TransactionDigest,
+    pub recipient: SuiAddress,
+    pub amount: u64,
+}
+
+impl ProofBuilder for NativeTransferTarget {
+    fn construct(self, checkpoint: &CheckpointData) -> ProofResult<Proof> {
+        let transaction_proof = TransactionProof::new(self.transaction_digest, checkpoint, false)?;
+
+        Ok(Proof {
+            targets: ProofTarget::NativeTransfer(self),
+            checkpoint_summary: checkpoint.checkpoint_summary.clone(),
+            proof_contents: ProofContents::TransactionProof(transaction_proof),
+        })
+    }
+}
@@ -10,13 +10,14 @@ use sui_types::{
     event::{Event, EventID},
     full_checkpoint_content::CheckpointData,
     messages_checkpoint::{CheckpointContents, VerifiedCheckpoint},
-    object::Object,
-    transaction::Transaction,
+    object::{Object, Owner},
+    transaction::{Transaction, TransactionDataAPI, TransactionKind},
 };
 
 use crate::proof::{
     base::{ProofContentsVerifier, ProofTarget},
     error::{ProofError, ProofResult},
+    native_transfer::NativeTransferTarget,
 };
 
 /// A proof that provides evidence relating to a specific transaction.
@@ -115,6 +116,34 @@ impl TransactionProof {
 
         Ok(())
     }
+
+    /// Check that the transaction is the target native transfer and that it succeeded
+    fn verify_native_transfer(&self, target: &NativeTransferTarget) -> ProofResult<()> {
+        if self.transaction.digest() != &target.transaction_digest {
+            return Err(ProofError::TransactionDigestMismatch);
+        }
+
+        let TransactionKind::NativeTransfer(transfer) =
+            self.transaction.data().transaction_data().kind()
+        else {
+            return Err(ProofError::NotANativeTransfer);
+        };
+        if transfer.recipient != target.recipient || transfer.amount != target.amount {
+            return Err(ProofError::NativeTransferMismatch);
+        }
+
+        if !self.effects.status().is_ok() {
+            return Err(ProofError::TransactionFailed);
+        }
+
+        // The recipient must own the coin created by the transfer
+        self.effects
+            .created()
+            .iter()
+            .find(|(_, owner)| owner == &Owner::AddressOwner(target.recipient))
+            .ok_or(ProofError::ObjectNotFound)?;
+        Ok(())
+    }
 }
 
 impl ProofContentsVerifier for TransactionProof {
@@ -146,6 +175,7 @@ impl ProofContentsVerifier for TransactionProof {
         match targets {
             ProofTarget::Objects(target) => self.verify_objects(&target.objects),
             ProofTarget::Events(target) => self.verify_events(&target.events, &digests.transaction),
+            ProofTarget::NativeTransfer(target) => self.verify_native_transfer(target),
             _ => Err(ProofError::MismatchedTargetAndProofType),
         }
     }
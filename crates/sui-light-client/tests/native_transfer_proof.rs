@@ -0,0 +1,171 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use sui_light_client::proof::{
+    base::{ProofBuilder, ProofContents, ProofTarget, ProofVerifier},
+    error::ProofError,
+};
+
+use sui_protocol_config::ProtocolConfig;
+use sui_types::{
+    base_types::{ObjectID, SuiAddress, random_object_ref},
+    committee::Committee,
+    crypto::{AccountKeyPair, get_key_pair},
+    effects::{TestEffectsBuilder, TransactionEffects, TransactionEffectsAPI},
+    execution_status::{ExecutionFailureStatus, ExecutionStatus},
+    full_checkpoint_content::{CheckpointData, CheckpointTransaction},
+    gas::GasCostSummary,
+    messages_checkpoint::{CertifiedCheckpointSummary, CheckpointContents, CheckpointSummary},
+    object::Owner,
+    transaction::{Transaction, TransactionData},
+    utils::to_sender_signed_transaction,
+};
+
+const AMOUNT: u64 = 1_000;
+
+fn native_transfer(recipient: SuiAddress) -> Transaction {
+    let (sender, key): (_, AccountKeyPair) = get_key_pair();
+    let data = TransactionData::new_native_transfer(sender, random_object_ref(), recipient, AMOUNT);
+    to_sender_signed_transaction(data, &key)
+}
+
+fn successful_effects(transaction: &Transaction, recipient: SuiAddress) -> TransactionEffects {
+    TestEffectsBuilder::new(transaction.data())
+        .with_created_objects([(ObjectID::random(), Owner::AddressOwner(recipient))])
+        .build()
+}
+
+// Builds a certified checkpoint holding a single transaction, along with its committee
+fn checkpoint_with(
+    transaction: Transaction,
+    effects: TransactionEffects,
+) -> (CheckpointData, Committee) {
+    let contents =
+        CheckpointContents::new_with_digests_only_for_tests([effects.execution_digests()]);
+    let summary = CheckpointSummary::new(
+        &ProtocolConfig::get_for_max_version_UNSAFE(),
+        0,
+        1,
+        1,
+        &contents,
+        None,
+        GasCostSummary::default(),
+        None,
+        0,
+        vec![],
+        vec![],
+    );
+    let (committee, keys) = Committee::new_simple_test_committee();
+    let checkpoint_summary =
+        CertifiedCheckpointSummary::new_from_keypairs_for_testing(summary, &keys, &committee);
+
+    let checkpoint = CheckpointData {
+        checkpoint_summary,
+        checkpoint_contents: contents,
+        transactions: vec![CheckpointTransaction {
+            transaction,
+            effects,
+            events: None,
+            input_objects: vec![],
+            output_objects: vec![],
+        }],
+    };
+    (checkpoint, committee)
+}
+
+#[test]
+fn test_native_transfer_proof_success() {
+    let (recipient, _): (_, AccountKeyPair) = get_key_pair();
+    let transaction = native_transfer(recipient);
+    let effects = successful_effects(&transaction, recipient);
+    let digest = *transaction.digest();
+    let (checkpoint, committee) = checkpoint_with(transaction, effects);
+
+    let proof = ProofTarget::new_native_transfer(digest, recipient, AMOUNT)
+        .construct(&checkpoint)
+        .unwrap();
+    assert!(proof.verify(&committee).is_ok());
+
+    // The proof only holds against the committee that certified the checkpoint
+    let proof = ProofTarget::new_native_transfer(digest, recipient, AMOUNT)
+        .construct(&checkpoint)
+        .unwrap();
+    let (other_committee, _) = Committee::new_simple_test_committee();
+    assert!(proof.verify(&other_committee).is_err());
+}
+
+#[test]
+fn test_native_transfer_proof_fail_tampered_effects() {
+    let (recipient, _): (_, AccountKeyPair) = get_key_pair();
+    let transaction = native_transfer(recipient);
+    let effects = successful_effects(&transaction, recipient);
+    let digest = *transaction.digest();
+    let (checkpoint, committee) = checkpoint_with(transaction.clone(), effects);
+
+    let mut proof = ProofTarget::new_native_transfer(digest, recipient, AMOUNT)
+        .construct(&checkpoint)
+        .unwrap();
+
+    // Swap in effects that were never committed to by the checkpoint
+    let ProofContents::TransactionProof(transaction_proof) = &mut proof.proof_contents else {
+        panic!("Expected a transaction proof");
+    };
+    transaction_proof.effects = TestEffectsBuilder::new(transaction.data())
+        .with_created_objects([(ObjectID::random(), Owner::AddressOwner(recipient))])
+        .build();
+    assert!(matches!(
+        proof.verify(&committee),
+        Err(ProofError::TransactionDigestNotFound)
+    ));
+}
+
+#[test]
+fn test_native_transfer_proof_fail_wrong_target() {
+    let (recipient, _): (_, AccountKeyPair) = get_key_pair();
+    let transaction = native_transfer(recipient);
+    let effects = successful_effects(&transaction, recipient);
+    let digest = *transaction.digest();
+    let (checkpoint, committee) = checkpoint_with(transaction, effects);
+
+    let proof = ProofTarget::new_native_transfer(digest, recipient, AMOUNT + 1)
+        .construct(&checkpoint)
+        .unwrap();
+    assert!(matches!(
+        proof.verify(&committee),
+        Err(ProofError::NativeTransferMismatch)
+    ));
+
+    let (someone_else, _): (_, AccountKeyPair) = get_key_pair();
+    let proof = ProofTarget::new_native_transfer(digest, someone_else, AMOUNT)
+        .construct(&checkpoint)
+        .unwrap();
+    assert!(matches!(
+        proof.verify(&committee),
+        Err(ProofError::NativeTransferMismatch)
+    ));
+}
+
+#[test]
+fn test_native_transfer_proof_fail_aborted_transfer() {
+    let (recipient, _): (_, AccountKeyPair) = get_key_pair();
+    let transaction = native_transfer(recipient);
+    let effects = TestEffectsBuilder::new(transaction.data())
+        .with_status(ExecutionStatus::new_failure(
+            ExecutionFailureStatus::InsufficientCoinBalanceForTransfer {
+                available: 0,
+                requested: AMOUNT,
+            },
+            None,
+        ))
+        .build();
+    let digest = *transaction.digest();
+    let (checkpoint, committee) = checkpoint_with(transaction, effects);
+
+    let proof = ProofTarget::new_native_transfer(digest, recipient, AMOUNT)
+        .construct(&checkpoint)
+        .unwrap();
+    assert!(matches!(
+        proof.verify(&committee),
+        Err(ProofError::TransactionFailed)
+    ));
+}
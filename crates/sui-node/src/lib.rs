@@ -105,6 +105,7 @@ use sui_core::epoch::reconfiguration::ReconfigurationInitiator;
 use sui_core::global_state_hasher::GlobalStateHasher;
 use sui_core::jsonrpc_index::IndexStore;
 use sui_core::module_cache_metrics::ResolverMetrics;
+use sui_core::native_transfer_reconciler::native_transfer_reconciler;
 use sui_core::overload_monitor::overload_monitor;
 use sui_core::rpc_index::RpcIndexStore;
 use sui_core::signature_verifier::SignatureVerifierMetrics;
@@ -1316,6 +1317,11 @@ impl SuiNode {
             None
         };
 
+        if config.enable_native_transfer_reconciler {
+            // Stops on its own once the authority state is dropped.
+            spawn_monitored_task!(native_transfer_reconciler(Arc::downgrade(&state)));
+        }
+
         Self::start_epoch_specific_validator_components(
             &config,
             state.clone(),
@@ -23,7 +23,7 @@ use tracing::{info, warn};
 
 /// The minimum and maximum protocol versions supported by this build.
 const MIN_PROTOCOL_VERSION: u64 = 1;
-const MAX_PROTOCOL_VERSION: u64 = 105;
+const MAX_PROTOCOL_VERSION: u64 = 106;
 
 // Record history of protocol version allocations here:
 //
@@ -280,6 +280,9 @@ const MAX_PROTOCOL_VERSION: u64 = 105;
 //              Enable all non-zero PCRs parsing for nitro attestation native function in Devnet and Testnet.
 // Version 105: Framework update: address aliases
 //              Enable address balances on devnet
+// Version 106: Enable native transfers in devnet only.
+//              Report native transfer inputs that are not coins with a dedicated status in devnet.
+//              Report insufficient native transfer balances with the amounts involved in devnet.
 
 #[derive(Copy, Clone, Debug, Hash, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
 pub struct ProtocolVersion(u64);
@@ -908,6 +911,24 @@ struct FeatureFlags {
     // If true, enable object funds withdraw.
     #[serde(skip_serializing_if = "is_false")]
     enable_object_funds_withdraw: bool,
+
+    // If true, native transfer transaction kinds are accepted for signing.
+    #[serde(skip_serializing_if = "is_false")]
+    native_transfer_enabled: bool,
+
+    // If true, native transfers to the zero address are rejected for signing.
+    #[serde(skip_serializing_if = "is_false")]
+    reject_native_transfer_to_zero_address: bool,
+
+    // If true, native transactions whose input is not a SUI coin fail with
+    // NativeTransferInputNotACoin instead of InvalidGasObject.
+    #[serde(skip_serializing_if = "is_false")]
+    native_transfer_not_a_coin_status: bool,
+
+    // If true, native transactions whose coin cannot cover the requested amount fail with
+    // InsufficientCoinBalanceForTransfer instead of InsufficientCoinBalance.
+    #[serde(skip_serializing_if = "is_false")]
+    native_insufficient_balance_status: bool,
 }
 
 fn is_false(b: &bool) -> bool {
@@ -1761,6 +1782,14 @@ pub struct ProtocolConfig {
 
     /// The maximum number of updates per settlement transaction.
     max_updates_per_settlement_txn: Option<u32>,
+
+    /// If set, a partial native transfer may not leave a non-zero remainder in the source coin
+    /// below this many MIST. Transfers that consume the whole coin are always allowed.
+    native_transfer_dust_threshold: Option<u64>,
+
+    /// If set, native transfers must move at least this many MIST to each recipient. When unset,
+    /// any non-zero amount is allowed, as if the minimum were 1.
+    min_native_transfer_amount: Option<u64>,
 }
 
 /// An aliased address.
@@ -2432,6 +2461,22 @@ impl ProtocolConfig {
         }
         address_aliases
     }
+
+    pub fn native_transfer_enabled(&self) -> bool {
+        self.feature_flags.native_transfer_enabled
+    }
+
+    pub fn reject_native_transfer_to_zero_address(&self) -> bool {
+        self.feature_flags.reject_native_transfer_to_zero_address
+    }
+
+    pub fn native_transfer_not_a_coin_status(&self) -> bool {
+        self.feature_flags.native_transfer_not_a_coin_status
+    }
+
+    pub fn native_insufficient_balance_status(&self) -> bool {
+        self.feature_flags.native_insufficient_balance_status
+    }
 }
 
 #[cfg(not(msim))]
@@ -3005,6 +3050,10 @@ impl ProtocolConfig {
             translation_per_linkage_entry_charge: None,
 
             max_updates_per_settlement_txn: None,
+
+            native_transfer_dust_threshold: None,
+
+            min_native_transfer_amount: None,
             // When adding a new constant, set it to None in the earliest version, like this:
             // new_constant: None,
         };
@@ -4328,6 +4377,14 @@ impl ProtocolConfig {
                         cfg.feature_flags.enable_authenticated_event_streams = true;
                     }
                 }
+                106 => {
+                    // Only enable native transfers on devnet.
+                    if chain != Chain::Mainnet && chain != Chain::Testnet {
+                        cfg.feature_flags.native_transfer_enabled = true;
+                        cfg.feature_flags.native_transfer_not_a_coin_status = true;
+                        cfg.feature_flags.native_insufficient_balance_status = true;
+                    }
+                }
                 // Use this template when making changes:
                 //
                 //     // modify an existing constant.
@@ -4706,6 +4763,22 @@ impl ProtocolConfig {
     pub fn set_enable_object_funds_withdraw_for_testing(&mut self, val: bool) {
         self.feature_flags.enable_object_funds_withdraw = val;
     }
+
+    pub fn set_native_transfer_enabled_for_testing(&mut self, val: bool) {
+        self.feature_flags.native_transfer_enabled = val;
+    }
+
+    pub fn set_reject_native_transfer_to_zero_address_for_testing(&mut self, val: bool) {
+        self.feature_flags.reject_native_transfer_to_zero_address = val;
+    }
+
+    pub fn set_native_transfer_not_a_coin_status_for_testing(&mut self, val: bool) {
+        self.feature_flags.native_transfer_not_a_coin_status = val;
+    }
+
+    pub fn set_native_insufficient_balance_status_for_testing(&mut self, val: bool) {
+        self.feature_flags.native_insufficient_balance_status = val;
+    }
 }
 
 type OverrideFn = dyn Fn(ProtocolVersion, ProtocolConfig) -> ProtocolConfig + Send;
@@ -269,6 +269,7 @@ impl ValidatorConfigBuilder {
             validator_client_monitor_config: None,
             fork_recovery: None,
             transaction_driver_config: Some(TransactionDriverConfig::default()),
+            enable_native_transfer_reconciler: false,
         }
     }
 
@@ -611,6 +612,7 @@ impl FullnodeConfigBuilder {
             transaction_driver_config: self
                 .transaction_driver_config
                 .or(Some(TransactionDriverConfig::default())),
+            enable_native_transfer_reconciler: false,
         }
     }
 }
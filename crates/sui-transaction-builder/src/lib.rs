@@ -176,6 +176,21 @@ impl TransactionBuilder {
         ))
     }
 
+    /// Builds an unmetered native transfer of `amount` from the coin `coin_id` to `recipient`,
+    /// using the coin's latest version.
+    pub async fn native_transfer(
+        &self,
+        signer: SuiAddress,
+        coin_id: ObjectID,
+        recipient: SuiAddress,
+        amount: u64,
+    ) -> anyhow::Result<TransactionData> {
+        let coin = self.get_object_ref(coin_id).await?;
+        Ok(TransactionData::new_native_transfer(
+            signer, coin, recipient, amount,
+        ))
+    }
+
     pub async fn pay_tx_kind(
         &self,
         input_coins: Vec<ObjectID>,
@@ -405,14 +405,21 @@ mod checked {
 
         let gas_coins: HashSet<ObjectID> =
             HashSet::from_iter(transaction.gas().iter().map(|obj_ref| obj_ref.0));
+        let recipient_coin = transaction.kind().recipient_owned_coin();
         for object in objects.iter() {
             let input_object_kind = object.input_object_kind;
 
             match &object.object {
                 ObjectReadResultKind::Object(object) => {
-                    // For Gas Object, we check the object is owned by gas owner
+                    // For Gas Object, we check the object is owned by gas owner, and for the
+                    // recipient's coin in a native transfer into it, by the recipient, who must
+                    // also have signed
                     let owner_address = if gas_coins.contains(&object.id()) {
                         transaction.gas_owner()
+                    } else if let Some((_, recipient)) =
+                        recipient_coin.filter(|(id, _)| *id == object.id())
+                    {
+                        recipient
                     } else {
                         transaction.sender()
                     };
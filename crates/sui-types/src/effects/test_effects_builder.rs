@@ -259,7 +259,12 @@ impl TestEffectsBuilder {
                 )
             }))
             .collect();
-        let gas_object_id = self.transaction.transaction_data().gas()[0].0;
+        let gas_object_id = self
+            .transaction
+            .transaction_data()
+            .gas()
+            .first()
+            .map(|gas| gas.0);
         let event_digest = self.events_digest;
         let dependencies = vec![];
         TransactionEffects::new_from_execution_v2(
@@ -271,7 +276,7 @@ impl TestEffectsBuilder {
             self.transaction.digest(),
             lamport_version,
             changed_objects,
-            Some(gas_object_id),
+            gas_object_id,
             event_digest,
             dependencies,
         )
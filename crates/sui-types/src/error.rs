@@ -300,6 +300,23 @@ pub enum UserInputError {
 
     #[error("Transaction chain ID {provided} does not match network chain ID {expected}.")]
     InvalidChainId { provided: String, expected: String },
+
+    #[error("Native transfer recipient {recipient} is the sender, who already owns the coin")]
+    NativeTransferToSelf { recipient: SuiAddress },
+
+    #[error("Native transfer amount {amount} is below the minimum of {minimum} MIST")]
+    NativeTransferBelowMinimum { amount: u64, minimum: u64 },
+
+    #[error("Native transfers to the zero address are not allowed")]
+    NativeTransferToZeroAddress,
+
+    #[error(
+        "Native transfer cannot execute before epoch {not_before_epoch}, current epoch is {current_epoch}"
+    )]
+    NativeTransferNotYetExecutable {
+        not_before_epoch: EpochId,
+        current_epoch: EpochId,
+    },
 }
 
 #[derive(
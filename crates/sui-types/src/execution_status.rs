@@ -7,6 +7,7 @@ use move_binary_format::file_format::{CodeOffset, TypeParameterIndex};
 use move_core_types::language_storage::ModuleId;
 use serde::{Deserialize, Serialize};
 use std::fmt::{self, Display, Formatter};
+use strum::IntoStaticStr;
 use sui_macros::EnumVariantOrder;
 use thiserror::Error;
 
@@ -38,7 +39,9 @@ impl fmt::Display for CongestedObjects {
     }
 }
 
-#[derive(Eq, PartialEq, Clone, Debug, Serialize, Deserialize, Error, EnumVariantOrder)]
+#[derive(
+    Eq, PartialEq, Clone, Debug, Serialize, Deserialize, Error, EnumVariantOrder, IntoStaticStr,
+)]
 pub enum ExecutionFailureStatus {
     //
     // General transaction errors
@@ -247,6 +250,33 @@ pub enum ExecutionFailureStatus {
 
     #[error("Non-exclusive write input object {id} has been modified")]
     NonExclusiveWriteInputObjectModified { id: ObjectID },
+
+    #[error(
+        "Native transfer would leave {remainder} MIST in the source coin, \
+        below the dust threshold of {threshold} MIST"
+    )]
+    NativeTransferDustRemainder { remainder: u64, threshold: u64 },
+
+    #[error(
+        "Native top-up target coin already holds {balance} MIST, \
+        which meets the target balance of {target_balance} MIST"
+    )]
+    NativeTopUpTargetAlreadyMet { balance: u64, target_balance: u64 },
+
+    #[error("Native merge input {id} is not a gas coin owned by the transaction sender")]
+    InvalidMergeInput { id: ObjectID },
+
+    #[error("Native transfer input {object_id} is not a SUI coin")]
+    NativeTransferInputNotACoin { object_id: ObjectID },
+
+    #[error(
+        "Native transfer source coin holds {available} MIST, \
+        but {requested} MIST were requested"
+    )]
+    InsufficientCoinBalanceForTransfer { available: u64, requested: u64 },
+
+    #[error("Native transfer moves the whole coin, leaving no change for {change_recipient}")]
+    NativeTransferNoChange { change_recipient: SuiAddress },
     // NOTE: if you want to add a new enum,
     // please add it at the end for Rust SDK backward compatibility.
 }
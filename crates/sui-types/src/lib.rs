@@ -75,6 +75,7 @@ pub mod metrics;
 pub mod move_package;
 pub mod multisig;
 pub mod multisig_legacy;
+pub mod native_transfer;
 pub mod nitro_attestation;
 pub mod object;
 pub mod passkey_authenticator;
@@ -0,0 +1,373 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use move_core_types::language_storage::TypeTag;
+use serde::{Deserialize, Serialize};
+use sui_protocol_config::ProtocolConfig;
+
+use crate::base_types::{ObjectID, ObjectRef, SequenceNumber, SuiAddress, TransactionDigest};
+use crate::effects::{TransactionEffects, TransactionEffectsAPI};
+use crate::error::UserInputError;
+use crate::gas_coin::GasCoin;
+use crate::messages_checkpoint::CheckpointSequenceNumber;
+use crate::object::Object;
+use crate::transaction::{
+    TransactionData, TransactionDataAPI, TransactionExpiration, TransactionKind,
+};
+
+#[cfg(test)]
+#[path = "unit_tests/native_transfer_tests.rs"]
+mod native_transfer_tests;
+
+/// The outcome of an executed native transfer, as read back from the transaction, its effects
+/// and the coins it wrote.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NativeTransferSummary {
+    pub transaction_digest: TransactionDigest,
+    pub sender: SuiAddress,
+    pub recipient: SuiAddress,
+    /// The coin the transfer was paid from.
+    pub source_coin: ObjectID,
+    /// Balance left in the source coin after the transfer.
+    pub source_remaining: u64,
+    /// The coin created for the recipient.
+    pub created_coin: ObjectID,
+    /// Version of the created coin. Like every object the transfer writes, it is created at the
+    /// transaction's lamport version: one past the highest input version.
+    pub created_coin_version: SequenceNumber,
+    /// Balance of the coin created for the recipient.
+    pub recipient_amount: u64,
+    /// Type of the coin created for the recipient, such as `0x2::sui::SUI`, so clients can tell
+    /// which coin they received without fetching it.
+    pub coin_type: TypeTag,
+    /// The source coin's balance before and after the transfer, if requested.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_balances: Option<NativeTransferSourceBalances>,
+}
+
+/// Balances of a native transfer's source coin around the transfer, so the transfer can be
+/// reconciled without looking up the coin's prior state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NativeTransferSourceBalances {
+    pub pre_balance: u64,
+    pub post_balance: u64,
+    /// The amount that left the source coin, `pre_balance - post_balance`.
+    pub delta: u64,
+}
+
+impl NativeTransferSummary {
+    /// Builds the summary of a successful native transfer. `output_objects` must hold the objects
+    /// written by the transaction at their output versions.
+    ///
+    /// Returns `None` if the transaction is not a `NativeTransfer` or `NativeTransferWithConsent`,
+    /// if it failed, or if either coin is missing from `output_objects`. Other native kinds do not
+    /// create exactly one coin for one recipient, so they have no summary; see
+    /// [`native_multi_transfer_ledger_rows`] for multi-recipient transfers.
+    pub fn new(
+        transaction: &TransactionData,
+        effects: &TransactionEffects,
+        output_objects: &[Object],
+    ) -> Option<Self> {
+        let (TransactionKind::NativeTransfer(transfer)
+        | TransactionKind::NativeTransferWithConsent(transfer)) = transaction.kind()
+        else {
+            return None;
+        };
+        if !effects.status().is_ok() {
+            return None;
+        }
+
+        let output_object = |id: ObjectID| output_objects.iter().find(|object| object.id() == id);
+        let coin_value = |id: ObjectID| {
+            output_object(id)
+                .and_then(|object| GasCoin::try_from(object).ok())
+                .map(|coin| coin.value())
+        };
+        let (created_coin, created_coin_version, _) = effects.created().first()?.0;
+
+        Some(Self {
+            transaction_digest: *effects.transaction_digest(),
+            sender: transaction.sender(),
+            recipient: transfer.recipient,
+            source_coin: transfer.coin.0,
+            source_remaining: coin_value(transfer.coin.0)?,
+            created_coin,
+            created_coin_version,
+            recipient_amount: coin_value(created_coin)?,
+            coin_type: output_object(created_coin)?.coin_type_maybe()?,
+            source_balances: None,
+        })
+    }
+
+    /// Adds the source coin's balance before and after the transfer. `input_objects` must hold
+    /// the source coin at the version the transfer consumed.
+    ///
+    /// Returns `None` if the source coin is missing from `input_objects`, or held less than it does
+    /// after the transfer.
+    pub fn with_source_balances(mut self, input_objects: &[Object]) -> Option<Self> {
+        let pre_balance = input_objects
+            .iter()
+            .find(|object| object.id() == self.source_coin)
+            .and_then(|object| GasCoin::try_from(object).ok())?
+            .value();
+        self.source_balances = Some(NativeTransferSourceBalances {
+            pre_balance,
+            post_balance: self.source_remaining,
+            delta: pre_balance.checked_sub(self.source_remaining)?,
+        });
+        Some(self)
+    }
+}
+
+/// The side of a double-entry ledger a row is booked on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LedgerSide {
+    Debit,
+    Credit,
+}
+
+/// One double-entry ledger row derived from a native transfer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NativeTransferLedgerRow {
+    pub transaction_digest: TransactionDigest,
+    pub side: LedgerSide,
+    pub owner: SuiAddress,
+    pub coin: ObjectID,
+    pub amount: u64,
+}
+
+impl NativeTransferSummary {
+    /// Books the transfer as a debit of the sender's source coin and a credit of the coin created
+    /// for the recipient. Native transfers charge no gas, so the two rows always balance.
+    pub fn ledger_rows(&self) -> Vec<NativeTransferLedgerRow> {
+        let row = |side, owner, coin| NativeTransferLedgerRow {
+            transaction_digest: self.transaction_digest,
+            side,
+            owner,
+            coin,
+            amount: self.recipient_amount,
+        };
+        vec![
+            row(LedgerSide::Debit, self.sender, self.source_coin),
+            row(LedgerSide::Credit, self.recipient, self.created_coin),
+        ]
+    }
+}
+
+/// Books a successful multi-recipient native transfer as a debit of the sender's source coin for
+/// the total sent, and one credit per recipient for the coin created for it, in the order of the
+/// transfer's `recipients`. The credits always add up to the debit.
+///
+/// Returns `None` if the transaction is not a `NativeMultiTransfer` or if it failed.
+pub fn native_multi_transfer_ledger_rows(
+    transaction: &TransactionData,
+    effects: &TransactionEffects,
+) -> Option<Vec<NativeTransferLedgerRow>> {
+    let TransactionKind::NativeMultiTransfer(transfer) = transaction.kind() else {
+        return None;
+    };
+    if !effects.status().is_ok() {
+        return None;
+    }
+
+    let row = |side, owner, coin, amount| NativeTransferLedgerRow {
+        transaction_digest: *effects.transaction_digest(),
+        side,
+        owner,
+        coin,
+        amount,
+    };
+    // Execution rejects transfers whose total overflows, so a successful one cannot.
+    let total = transfer.recipients.iter().map(|(_, amount)| amount).sum();
+    let debit = row(
+        LedgerSide::Debit,
+        transaction.sender(),
+        transfer.coin.0,
+        total,
+    );
+    let credits = transfer
+        .recipients
+        .iter()
+        .zip(transaction.native_multi_transfer_created_coin_ids()?)
+        .map(|(&(recipient, amount), coin)| row(LedgerSide::Credit, recipient, coin, amount));
+    Some(std::iter::once(debit).chain(credits).collect())
+}
+
+/// One version of a coin written by a native transaction.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NativeTransferLineageEntry {
+    pub version: SequenceNumber,
+    pub transaction_digest: TransactionDigest,
+    /// Balance of the coin at `version`.
+    pub balance: u64,
+}
+
+/// Canonical position of a native transfer output on chain: the checkpoint that includes it, the
+/// transaction's position within that checkpoint, then the output's position among the coins the
+/// transaction sends. A single-recipient transfer has one output, at index 0; a multi-recipient
+/// transfer numbers its outputs in the order of its `recipients`.
+///
+/// Keys order the same way on every node, which makes them stable pagination cursors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct NativeTransferSortKey {
+    pub checkpoint: CheckpointSequenceNumber,
+    pub transaction_index: u64,
+    pub output_index: u64,
+}
+
+impl NativeTransferSortKey {
+    /// A cursor that sorts after every output in `checkpoint` and before those of the next one.
+    /// It is not the key of any transfer.
+    pub fn end_of_checkpoint(checkpoint: CheckpointSequenceNumber) -> Self {
+        Self {
+            checkpoint,
+            transaction_index: u64::MAX,
+            output_index: u64::MAX,
+        }
+    }
+}
+
+/// A page of native transfers, in sort key order.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NativeTransferPage {
+    pub transfers: Vec<(NativeTransferSortKey, NativeTransferSummary)>,
+    /// The cursor to pass for the next page: the last transfer's key if the page is full,
+    /// otherwise the end of the last checkpoint scanned. `None` if no checkpoint was scanned and
+    /// no cursor was given.
+    pub next_cursor: Option<NativeTransferSortKey>,
+}
+
+/// Source coins chosen for a native transfer, from least to most fragmenting.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NativeTransferCoinSelection {
+    /// A coin holding exactly the amount; it is consumed whole and leaves no change behind.
+    ExactFit(ObjectRef),
+    /// The smallest coin holding more than the amount, which keeps the leftover change small.
+    SmallestSufficient(ObjectRef),
+    /// No single coin is large enough: the fewest coins that cover the amount, largest first.
+    /// They must be merged into the first one before transferring.
+    Aggregate(Vec<ObjectRef>),
+}
+
+/// Chooses which of `coins` (with their balances) should fund a native transfer of `amount`,
+/// minimizing the coins left behind. Returns `None` if the coins together cannot cover it, or if
+/// `amount` is zero, since native transfers must move a non-zero amount.
+pub fn select_native_transfer_coins(
+    coins: &[(ObjectRef, u64)],
+    amount: u64,
+) -> Option<NativeTransferCoinSelection> {
+    if amount == 0 {
+        return None;
+    }
+
+    if let Some((coin, _)) = coins.iter().find(|(_, balance)| *balance == amount) {
+        return Some(NativeTransferCoinSelection::ExactFit(*coin));
+    }
+
+    if let Some((coin, _)) = coins
+        .iter()
+        .filter(|(_, balance)| *balance > amount)
+        .min_by_key(|(coin, balance)| (*balance, coin.0))
+    {
+        return Some(NativeTransferCoinSelection::SmallestSufficient(*coin));
+    }
+
+    // Taking the largest coins first covers the amount with as few coins as possible.
+    let mut sorted: Vec<_> = coins.iter().collect();
+    sorted.sort_by_key(|(coin, balance)| (std::cmp::Reverse(*balance), coin.0));
+    let mut selected = vec![];
+    let mut total = 0u128;
+    for (coin, balance) in sorted {
+        if total >= amount as u128 {
+            break;
+        }
+        selected.push(*coin);
+        total += *balance as u128;
+    }
+    (total >= amount as u128).then_some(NativeTransferCoinSelection::Aggregate(selected))
+}
+
+/// Runs `validity_check` on a native transaction and, if it fails, explains which rule was
+/// violated and by which values. Returns `None` if the transaction passes.
+pub fn explain_native_transfer_rejection(
+    tx_data: &TransactionData,
+    config: &ProtocolConfig,
+) -> Option<String> {
+    let err = tx_data.validity_check(config).err()?;
+
+    // The kind-level rules are checked last, so only blame them when they are what failed. The
+    // remaining errors come from the transaction data: its gas data and expiration.
+    let kind_failed = tx_data.kind().validity_check(config).err().as_ref() == Some(&err);
+    let explanation = if kind_failed {
+        match tx_data.kind() {
+            kind if !config.native_transfer_enabled() => format!(
+                "{} is rejected because native transfers are not enabled at protocol version {}",
+                kind.name(),
+                config.version.as_u64()
+            ),
+            kind if config.execution_version_as_option() != Some(2) => format!(
+                "{} is rejected because protocol version {} does not run the native execution engine",
+                kind.name(),
+                config.version.as_u64()
+            ),
+            TransactionKind::NativeTransfer(transfer)
+            | TransactionKind::NativeTransferWithConsent(transfer)
+                if transfer.amount == 0 && matches!(err, UserInputError::Unsupported(_)) =>
+            {
+                format!(
+                    "amount {} violates the rule that a native transfer must move a non-zero amount",
+                    transfer.amount
+                )
+            }
+            TransactionKind::NativeTopUp(top_up)
+                if top_up.target_balance == 0 && matches!(err, UserInputError::Unsupported(_)) =>
+            {
+                format!(
+                    "target balance {} violates the rule that a native top-up must target a non-zero \
+                     balance",
+                    top_up.target_balance
+                )
+            }
+            // The other kind-level rules already name the offending values in their errors.
+            _ => err.to_string(),
+        }
+    } else {
+        match &err {
+            UserInputError::UnsupportedSponsoredTransactionKind => format!(
+                "gas owner {} differs from sender {}, but only native transfers can be sponsored",
+                tx_data.gas_owner(),
+                tx_data.sender()
+            ),
+            UserInputError::GasPriceTooHigh { max_gas_price } => format!(
+                "gas price {} violates the maximum gas price of {max_gas_price}",
+                tx_data.gas_price()
+            ),
+            UserInputError::GasBudgetTooHigh {
+                gas_budget,
+                max_budget,
+            } => {
+                format!("gas budget {gas_budget} violates the maximum gas budget of {max_budget}")
+            }
+            UserInputError::SizeLimitExceeded { limit, value } => format!(
+                "{} gas payment objects violate the {limit} of {value}",
+                tx_data.gas().len()
+            ),
+            // Besides the expiration checks, only funds withdrawals report `Unsupported` here, and
+            // only a `ValidDuring` expiration can be unsupported.
+            UserInputError::Unsupported(reason)
+                if matches!(
+                    tx_data.expiration(),
+                    TransactionExpiration::ValidDuring { .. }
+                ) && !tx_data.has_funds_withdrawals() =>
+            {
+                format!(
+                    "expiration {:?} is not supported: {reason}",
+                    tx_data.expiration()
+                )
+            }
+            // Anything else is explained by the error itself.
+            _ => err.to_string(),
+        }
+    };
+    Some(explanation)
+}
@@ -1168,6 +1168,15 @@ impl From<crate::execution_status::ExecutionFailureStatus> for ExecutionError {
             E::NonExclusiveWriteInputObjectModified { .. } => {
                 todo!("Add NonExclusiveWriteInputObjectModified to rpc sdk")
             }
+            // The proto has no native transfer errors, so these map to the closest generic kind.
+            // The exact status is still carried in the description.
+            E::InsufficientCoinBalanceForTransfer { .. }
+            | E::NativeTransferDustRemainder { .. }
+            | E::NativeTransferNoChange { .. } => ExecutionErrorKind::InsufficientCoinBalance,
+            E::NativeTransferInputNotACoin { .. } | E::InvalidMergeInput { .. } => {
+                ExecutionErrorKind::InvalidGasObject
+            }
+            E::NativeTopUpTargetAlreadyMet { .. } => ExecutionErrorKind::InvalidTransferObject,
         };
 
         message.set_kind(kind);
@@ -2108,8 +2117,18 @@ impl From<crate::transaction::TransactionKind> for TransactionKind {
             // TODO support ProgrammableSystemTransaction
             // .with_programmable_transaction(ptb)
             // .with_kind(Kind::ProgrammableSystemTransaction),
-            K::NativeTransfer(_) => message,
-            // TODO support NativeTransfer in RPC proto
+            K::NativeTransfer(_)
+            | K::NativeTopUp(_)
+            | K::NativeMultiTransfer(_)
+            | K::NativeMerge(_)
+            | K::NativeTransferWhole(_)
+            | K::NativeTransferWithChange(_)
+            | K::NativeSweep(_)
+            | K::NativeTransferAfterEpoch(_)
+            | K::NativeSplitEqual(_)
+            | K::NativeTransferInto(_)
+            | K::NativeTransferWithConsent(_) => message,
+            // TODO support native transactions in RPC proto
         }
     }
 }
@@ -887,6 +887,13 @@ impl From<crate::execution_status::ExecutionFailureStatus> for ExecutionError {
             crate::execution_status::ExecutionFailureStatus::NonExclusiveWriteInputObjectModified { .. } => {
                 todo!("Add NonExclusiveWriteInputObjectModified to sdk")
             }
+            // The SDK has no native transfer errors, so these map to the closest generic error.
+            crate::execution_status::ExecutionFailureStatus::InsufficientCoinBalanceForTransfer { .. }
+            | crate::execution_status::ExecutionFailureStatus::NativeTransferDustRemainder { .. }
+            | crate::execution_status::ExecutionFailureStatus::NativeTransferNoChange { .. } => Self::InsufficientCoinBalance,
+            crate::execution_status::ExecutionFailureStatus::NativeTransferInputNotACoin { .. }
+            | crate::execution_status::ExecutionFailureStatus::InvalidMergeInput { .. } => Self::InvalidGasObject,
+            crate::execution_status::ExecutionFailureStatus::NativeTopUpTargetAlreadyMet { .. } => Self::InvalidTransferObject,
         }
     }
 }
@@ -55,6 +55,7 @@ use std::{
     iter,
 };
 use strum::IntoStaticStr;
+use sui_macros::EnumVariantOrder;
 use sui_protocol_config::{PerObjectCongestionControlMode, ProtocolConfig};
 use tap::Pipe;
 use tracing::trace;
@@ -454,7 +455,118 @@ pub struct NativeTransfer {
     pub amount: u64,
 }
 
-#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize, IntoStaticStr)]
+/// A native top-up that moves just enough SUI from one coin into another to bring the latter up
+/// to a target balance. Like `NativeTransfer`, it bypasses Move VM and doesn't charge gas.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+pub struct NativeTopUp {
+    /// The coin to take the funds from (must be owned by sender)
+    pub source: ObjectRef,
+    /// The coin to top up (must be owned by sender)
+    pub target: ObjectRef,
+    /// The balance the target coin should hold after the top-up
+    pub target_balance: u64,
+}
+
+/// A native transfer that splits one coin across several recipients, creating a new coin for
+/// each. Like `NativeTransfer`, it bypasses Move VM and doesn't charge gas.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+pub struct NativeMultiTransfer {
+    /// The coin object to transfer from (must be owned by sender)
+    pub coin: ObjectRef,
+    /// The recipients and the amount each receives (their sum must be <= coin balance)
+    pub recipients: Vec<(SuiAddress, u64)>,
+}
+
+/// A native merge that folds the balances of several coins into a primary coin and deletes the
+/// merged coins. Like `NativeTransfer`, it bypasses Move VM and doesn't charge gas.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+pub struct NativeMerge {
+    /// The coin that receives the merged balances (must be owned by sender)
+    pub primary_coin: ObjectRef,
+    /// The coins to merge into the primary coin and delete (must be owned by sender)
+    pub coins_to_merge: Vec<ObjectRef>,
+}
+
+/// A native transfer that hands a whole coin to the recipient by changing its owner in place,
+/// instead of splitting off a new coin. Like `NativeTransfer`, it bypasses Move VM and doesn't
+/// charge gas.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+pub struct NativeTransferWhole {
+    /// The coin object to transfer (must be owned by sender)
+    pub coin: ObjectRef,
+    /// The recipient address
+    pub recipient: SuiAddress,
+}
+
+/// A native transfer that splits a coin between the recipient and a change recipient, deleting
+/// the source coin. Like `NativeTransfer`, it bypasses Move VM and doesn't charge gas.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+pub struct NativeTransferWithChange {
+    /// The coin object to split (must be owned by sender)
+    pub coin: ObjectRef,
+    /// The recipient address
+    pub recipient: SuiAddress,
+    /// The amount to transfer (must be < coin balance, so that there is change to send)
+    pub amount: u64,
+    /// The address that receives the rest of the coin's balance
+    pub change_recipient: SuiAddress,
+}
+
+/// A native sweep that hands several whole coins to the recipient by changing their owners in
+/// place. Like `NativeTransfer`, it bypasses Move VM and doesn't charge gas.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+pub struct NativeSweep {
+    /// The coins to transfer (must be owned by sender)
+    pub coins: Vec<ObjectRef>,
+    /// The recipient address
+    pub recipient: SuiAddress,
+}
+
+/// A native transfer that validators refuse to sign before `not_before_epoch`. Once that epoch
+/// arrives it executes exactly like `NativeTransfer`, bypassing Move VM without charging gas.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+pub struct NativeTransferAfterEpoch {
+    /// The coin to transfer from (must be owned by sender)
+    pub coin: ObjectRef,
+    /// The recipient address
+    pub recipient: SuiAddress,
+    /// Amount to transfer in MIST
+    pub amount: u64,
+    /// The first epoch in which the transfer can be signed and executed
+    pub not_before_epoch: EpochId,
+}
+
+/// A native split that replaces a coin with `parts` new coins of equal value for the recipient,
+/// the last one also taking the remainder. Like `NativeTransfer`, it bypasses Move VM and doesn't
+/// charge gas.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+pub struct NativeSplitEqual {
+    /// The coin to split (must be owned by sender). It is deleted
+    pub coin: ObjectRef,
+    /// Number of coins to create
+    pub parts: u32,
+    /// The recipient of every new coin
+    pub recipient: SuiAddress,
+}
+
+/// A native transfer that adds the amount to a coin the recipient already owns instead of
+/// creating a new one. The recipient must co-sign it, since their coin is an input. Like
+/// `NativeTransfer`, it bypasses Move VM and doesn't charge gas.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+pub struct NativeTransferInto {
+    /// The coin to transfer from (must be owned by sender)
+    pub coin: ObjectRef,
+    /// The recipient, who must own `recipient_coin` and co-sign the transaction
+    pub recipient: SuiAddress,
+    /// The recipient's coin that receives the amount
+    pub recipient_coin: ObjectRef,
+    /// The amount to transfer (must be <= coin balance)
+    pub amount: u64,
+}
+
+#[derive(
+    Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize, IntoStaticStr, EnumVariantOrder,
+)]
 pub enum TransactionKind {
     /// A transaction that allows the interleaving of native commands and Move calls
     ProgrammableTransaction(ProgrammableTransaction),
@@ -489,6 +601,31 @@ pub enum TransactionKind {
     ProgrammableSystemTransaction(ProgrammableTransaction),
     /// A native transfer transaction that bypasses Move VM and doesn't charge gas
     NativeTransfer(NativeTransfer),
+    /// A native top-up of one coin from another, bypassing Move VM without charging gas
+    NativeTopUp(NativeTopUp),
+    /// A native transfer from one coin to several recipients, bypassing Move VM without charging
+    /// gas
+    NativeMultiTransfer(NativeMultiTransfer),
+    /// A native merge of several coins into one, bypassing Move VM without charging gas
+    NativeMerge(NativeMerge),
+    /// A native transfer of a whole coin, bypassing Move VM without charging gas
+    NativeTransferWhole(NativeTransferWhole),
+    /// A native transfer that sends the change to a third party, bypassing Move VM without
+    /// charging gas
+    NativeTransferWithChange(NativeTransferWithChange),
+    /// A native transfer of several whole coins, bypassing Move VM without charging gas
+    NativeSweep(NativeSweep),
+    /// A native transfer that cannot execute before a given epoch, bypassing Move VM without
+    /// charging gas
+    NativeTransferAfterEpoch(NativeTransferAfterEpoch),
+    /// A native split of a coin into equal parts, bypassing Move VM without charging gas
+    NativeSplitEqual(NativeSplitEqual),
+    /// A native transfer into a coin the recipient already owns, bypassing Move VM without
+    /// charging gas
+    NativeTransferInto(NativeTransferInto),
+    /// A native transfer that the recipient must co-sign to consent to receiving the coin,
+    /// bypassing Move VM without charging gas
+    NativeTransferWithConsent(NativeTransfer),
     // .. more transaction types go here
 }
 
@@ -1530,13 +1667,70 @@ impl TransactionKind {
             | TransactionKind::EndOfEpochTransaction(_)
             | TransactionKind::ProgrammableSystemTransaction(_) => true,
             TransactionKind::ProgrammableTransaction(_) => false,
-            TransactionKind::NativeTransfer(_) => false,
+            TransactionKind::NativeTransfer(_)
+            | TransactionKind::NativeTopUp(_)
+            | TransactionKind::NativeMultiTransfer(_)
+            | TransactionKind::NativeMerge(_)
+            | TransactionKind::NativeTransferWhole(_)
+            | TransactionKind::NativeTransferWithChange(_)
+            | TransactionKind::NativeSweep(_)
+            | TransactionKind::NativeTransferAfterEpoch(_)
+            | TransactionKind::NativeSplitEqual(_)
+            | TransactionKind::NativeTransferInto(_)
+            | TransactionKind::NativeTransferWithConsent(_) => false,
         }
     }
 
     /// Returns true if this transaction type should use unmetered gas (no gas charging)
     pub fn is_unmetered(&self) -> bool {
-        matches!(self, TransactionKind::NativeTransfer(_))
+        matches!(
+            self,
+            TransactionKind::NativeTransfer(_)
+                | TransactionKind::NativeTopUp(_)
+                | TransactionKind::NativeMultiTransfer(_)
+                | TransactionKind::NativeMerge(_)
+                | TransactionKind::NativeTransferWhole(_)
+                | TransactionKind::NativeTransferWithChange(_)
+                | TransactionKind::NativeSweep(_)
+                | TransactionKind::NativeTransferAfterEpoch(_)
+                | TransactionKind::NativeSplitEqual(_)
+                | TransactionKind::NativeTransferInto(_)
+                | TransactionKind::NativeTransferWithConsent(_)
+        )
+    }
+
+    /// Returns the addresses a native transaction sends coins to, including change recipients.
+    fn native_transfer_recipients(&self) -> Vec<SuiAddress> {
+        match self {
+            TransactionKind::NativeTransfer(transfer)
+            | TransactionKind::NativeTransferWithConsent(transfer) => vec![transfer.recipient],
+            TransactionKind::NativeMultiTransfer(transfer) => transfer
+                .recipients
+                .iter()
+                .map(|(recipient, _)| *recipient)
+                .collect(),
+            TransactionKind::NativeTransferWhole(transfer) => vec![transfer.recipient],
+            TransactionKind::NativeTransferWithChange(transfer) => {
+                vec![transfer.recipient, transfer.change_recipient]
+            }
+            TransactionKind::NativeSweep(sweep) => vec![sweep.recipient],
+            TransactionKind::NativeTransferAfterEpoch(transfer) => vec![transfer.recipient],
+            TransactionKind::NativeSplitEqual(split) => vec![split.recipient],
+            TransactionKind::NativeTransferInto(transfer) => vec![transfer.recipient],
+            _ => vec![],
+        }
+    }
+
+    /// The coin a `NativeTransferInto` credits, with the recipient who owns it. The recipient
+    /// co-signs the transaction, so input checks hold this coin to the recipient's ownership
+    /// rather than the sender's.
+    pub fn recipient_owned_coin(&self) -> Option<(ObjectID, SuiAddress)> {
+        match self {
+            TransactionKind::NativeTransferInto(transfer) => {
+                Some((transfer.recipient_coin.0, transfer.recipient))
+            }
+            _ => None,
+        }
     }
 
     pub fn is_end_of_epoch_tx(&self) -> bool {
@@ -1605,9 +1799,18 @@ impl TransactionKind {
             Self::ProgrammableTransaction(pt) | Self::ProgrammableSystemTransaction(pt) => {
                 Either::Right(Either::Left(pt.shared_input_objects()))
             }
-            Self::Genesis(_) | Self::NativeTransfer(_) => {
-                Either::Right(Either::Right(iter::empty()))
-            }
+            Self::Genesis(_)
+            | Self::NativeTransfer(_)
+            | Self::NativeTopUp(_)
+            | Self::NativeMultiTransfer(_)
+            | Self::NativeMerge(_)
+            | Self::NativeTransferWhole(_)
+            | Self::NativeTransferWithChange(_)
+            | Self::NativeSweep(_)
+            | Self::NativeTransferAfterEpoch(_)
+            | Self::NativeSplitEqual(_)
+            | Self::NativeTransferInto(_)
+            | Self::NativeTransferWithConsent(_) => Either::Right(Either::Right(iter::empty())),
         }
     }
 
@@ -1630,7 +1833,17 @@ impl TransactionKind {
             | TransactionKind::RandomnessStateUpdate(_)
             | TransactionKind::EndOfEpochTransaction(_)
             | TransactionKind::ProgrammableSystemTransaction(_)
-            | TransactionKind::NativeTransfer(_) => vec![],
+            | TransactionKind::NativeTransfer(_)
+            | TransactionKind::NativeTopUp(_)
+            | TransactionKind::NativeMultiTransfer(_)
+            | TransactionKind::NativeMerge(_)
+            | TransactionKind::NativeTransferWhole(_)
+            | TransactionKind::NativeTransferWithChange(_)
+            | TransactionKind::NativeSweep(_)
+            | TransactionKind::NativeTransferAfterEpoch(_)
+            | TransactionKind::NativeSplitEqual(_)
+            | TransactionKind::NativeTransferInto(_)
+            | TransactionKind::NativeTransferWithConsent(_) => vec![],
             TransactionKind::ProgrammableTransaction(pt) => pt.receiving_objects(),
         }
     }
@@ -1692,9 +1905,44 @@ impl TransactionKind {
             Self::ProgrammableTransaction(p) | Self::ProgrammableSystemTransaction(p) => {
                 return p.input_objects();
             }
-            Self::NativeTransfer(transfer) => {
+            Self::NativeTransfer(transfer) | Self::NativeTransferWithConsent(transfer) => {
                 vec![InputObjectKind::ImmOrOwnedMoveObject(transfer.coin)]
             }
+            Self::NativeTopUp(top_up) => {
+                vec![
+                    InputObjectKind::ImmOrOwnedMoveObject(top_up.source),
+                    InputObjectKind::ImmOrOwnedMoveObject(top_up.target),
+                ]
+            }
+            Self::NativeMultiTransfer(transfer) => {
+                vec![InputObjectKind::ImmOrOwnedMoveObject(transfer.coin)]
+            }
+            Self::NativeMerge(merge) => once(merge.primary_coin)
+                .chain(merge.coins_to_merge.iter().copied())
+                .map(InputObjectKind::ImmOrOwnedMoveObject)
+                .collect(),
+            Self::NativeTransferWhole(transfer) => {
+                vec![InputObjectKind::ImmOrOwnedMoveObject(transfer.coin)]
+            }
+            Self::NativeTransferWithChange(transfer) => {
+                vec![InputObjectKind::ImmOrOwnedMoveObject(transfer.coin)]
+            }
+            Self::NativeSweep(sweep) => sweep
+                .coins
+                .iter()
+                .copied()
+                .map(InputObjectKind::ImmOrOwnedMoveObject)
+                .collect(),
+            Self::NativeTransferAfterEpoch(transfer) => {
+                vec![InputObjectKind::ImmOrOwnedMoveObject(transfer.coin)]
+            }
+            Self::NativeSplitEqual(split) => {
+                vec![InputObjectKind::ImmOrOwnedMoveObject(split.coin)]
+            }
+            Self::NativeTransferInto(transfer) => vec![
+                InputObjectKind::ImmOrOwnedMoveObject(transfer.coin),
+                InputObjectKind::ImmOrOwnedMoveObject(transfer.recipient_coin),
+            ],
         };
         // Ensure that there are no duplicate inputs. This cannot be removed because:
         // In [`AuthorityState::check_locks`], we check that there are no duplicate mutable
@@ -1778,20 +2026,166 @@ impl TransactionKind {
                     ));
                 }
             }
-            TransactionKind::NativeTransfer(transfer) => {
+            TransactionKind::NativeTransfer(_)
+            | TransactionKind::NativeTopUp(_)
+            | TransactionKind::NativeMultiTransfer(_)
+            | TransactionKind::NativeMerge(_)
+            | TransactionKind::NativeTransferWhole(_)
+            | TransactionKind::NativeTransferWithChange(_)
+            | TransactionKind::NativeSweep(_)
+            | TransactionKind::NativeTransferAfterEpoch(_)
+            | TransactionKind::NativeSplitEqual(_)
+            | TransactionKind::NativeTransferInto(_)
+            | TransactionKind::NativeTransferWithConsent(_) => {
+                self.native_validity_check(config)?
+            }
+            TransactionKind::ProgrammableSystemTransaction(_) => {
+                if !config.enable_accumulators() {
+                    return Err(UserInputError::Unsupported(
+                        "accumulators not enabled".to_string(),
+                    ));
+                }
+            }
+        };
+        Ok(())
+    }
+
+    /// Checks the rules specific to native transactions. Like the rest of `validity_check`, it
+    /// only reads the transaction and `config`.
+    fn native_validity_check(&self, config: &ProtocolConfig) -> UserInputResult {
+        if !config.native_transfer_enabled() {
+            return Err(UserInputError::Unsupported(format!(
+                "{} is not supported",
+                self.name()
+            )));
+        }
+        // Only execution version 2 has a native executor, and native transactions carry no gas
+        // payment to run them as metered Move instead.
+        if config.execution_version_as_option() != Some(2) {
+            return Err(UserInputError::Unsupported(format!(
+                "{} requires execution version 2",
+                self.name()
+            )));
+        }
+        if config.reject_native_transfer_to_zero_address() {
+            fp_ensure!(
+                !self
+                    .native_transfer_recipients()
+                    .contains(&SuiAddress::ZERO),
+                UserInputError::NativeTransferToZeroAddress
+            );
+        }
+        match self {
+            TransactionKind::NativeTransfer(transfer)
+            | TransactionKind::NativeTransferWithConsent(transfer) => {
                 if transfer.amount == 0 {
                     return Err(UserInputError::Unsupported(
                         "Transfer amount must be greater than zero".to_string(),
                     ));
                 }
+                check_min_native_transfer_amount(transfer.amount, config)?;
             }
-            TransactionKind::ProgrammableSystemTransaction(_) => {
-                if !config.enable_accumulators() {
+            TransactionKind::NativeTopUp(top_up) => {
+                if top_up.target_balance == 0 {
                     return Err(UserInputError::Unsupported(
-                        "accumulators not enabled".to_string(),
+                        "Top-up target balance must be greater than zero".to_string(),
+                    ));
+                }
+            }
+            TransactionKind::NativeMultiTransfer(transfer) => {
+                if transfer.recipients.is_empty() {
+                    return Err(UserInputError::Unsupported(
+                        "Multi-recipient transfer must have at least one recipient".to_string(),
                     ));
                 }
+                fp_ensure!(
+                    transfer.recipients.len() as u64 <= config.max_num_new_move_object_ids(),
+                    UserInputError::SizeLimitExceeded {
+                        limit: "maximum recipients in a native transfer".to_string(),
+                        value: config.max_num_new_move_object_ids().to_string()
+                    }
+                );
+                if transfer.recipients.iter().any(|(_, amount)| *amount == 0) {
+                    return Err(UserInputError::Unsupported(
+                        "Transfer amount must be greater than zero".to_string(),
+                    ));
+                }
+                for (_, amount) in &transfer.recipients {
+                    check_min_native_transfer_amount(*amount, config)?;
+                }
+            }
+            TransactionKind::NativeMerge(merge) => {
+                if merge.coins_to_merge.is_empty() {
+                    return Err(UserInputError::Unsupported(
+                        "Native merge must have at least one coin to merge".to_string(),
+                    ));
+                }
+                fp_ensure!(
+                    merge.coins_to_merge.len() < config.max_input_objects() as usize,
+                    UserInputError::SizeLimitExceeded {
+                        limit: "maximum input + receiving objects in a transaction".to_string(),
+                        value: config.max_input_objects().to_string()
+                    }
+                );
             }
+            // The whole coin moves, so there is no amount to check.
+            TransactionKind::NativeTransferWhole(_) => (),
+            TransactionKind::NativeTransferWithChange(transfer) => {
+                if transfer.amount == 0 {
+                    return Err(UserInputError::Unsupported(
+                        "Transfer amount must be greater than zero".to_string(),
+                    ));
+                }
+                check_min_native_transfer_amount(transfer.amount, config)?;
+            }
+            TransactionKind::NativeSweep(sweep) => {
+                if sweep.coins.is_empty() {
+                    return Err(UserInputError::Unsupported(
+                        "Native sweep must have at least one coin".to_string(),
+                    ));
+                }
+                fp_ensure!(
+                    sweep.coins.len() <= config.max_input_objects() as usize,
+                    UserInputError::SizeLimitExceeded {
+                        limit: "maximum input + receiving objects in a transaction".to_string(),
+                        value: config.max_input_objects().to_string()
+                    }
+                );
+            }
+            TransactionKind::NativeTransferAfterEpoch(transfer) => {
+                if transfer.amount == 0 {
+                    return Err(UserInputError::Unsupported(
+                        "Transfer amount must be greater than zero".to_string(),
+                    ));
+                }
+                check_min_native_transfer_amount(transfer.amount, config)?;
+            }
+            TransactionKind::NativeSplitEqual(split) => {
+                // Whether the coin holds at least one MIST per part is only known at execution
+                if split.parts == 0 {
+                    return Err(UserInputError::Unsupported(
+                        "Native split must have at least one part".to_string(),
+                    ));
+                }
+                fp_ensure!(
+                    u64::from(split.parts) <= config.max_num_new_move_object_ids(),
+                    UserInputError::SizeLimitExceeded {
+                        limit: "maximum parts in a native split".to_string(),
+                        value: config.max_num_new_move_object_ids().to_string()
+                    }
+                );
+            }
+            // Overflow of the recipient's balance is only known at execution
+            TransactionKind::NativeTransferInto(transfer) => {
+                if transfer.amount == 0 {
+                    return Err(UserInputError::Unsupported(
+                        "Transfer amount must be greater than zero".to_string(),
+                    ));
+                }
+                check_min_native_transfer_amount(transfer.amount, config)?;
+            }
+            // Not a native transaction, so there is nothing more to check.
+            _ => (),
         };
         Ok(())
     }
@@ -1833,6 +2227,16 @@ impl TransactionKind {
             Self::RandomnessStateUpdate(_) => "RandomnessStateUpdate",
             Self::EndOfEpochTransaction(_) => "EndOfEpochTransaction",
             Self::NativeTransfer(_) => "NativeTransfer",
+            Self::NativeTopUp(_) => "NativeTopUp",
+            Self::NativeMultiTransfer(_) => "NativeMultiTransfer",
+            Self::NativeMerge(_) => "NativeMerge",
+            Self::NativeTransferWhole(_) => "NativeTransferWhole",
+            Self::NativeTransferWithChange(_) => "NativeTransferWithChange",
+            Self::NativeSweep(_) => "NativeSweep",
+            Self::NativeTransferAfterEpoch(_) => "NativeTransferAfterEpoch",
+            Self::NativeSplitEqual(_) => "NativeSplitEqual",
+            Self::NativeTransferInto(_) => "NativeTransferInto",
+            Self::NativeTransferWithConsent(_) => "NativeTransferWithConsent",
         }
     }
 }
@@ -1909,11 +2313,108 @@ impl Display for TransactionKind {
                 writeln!(writer, "Recipient: {}", transfer.recipient)?;
                 writeln!(writer, "Amount: {}", transfer.amount)?;
             }
+            Self::NativeTopUp(top_up) => {
+                writeln!(writer, "Transaction Kind : Native Top-Up")?;
+                writeln!(writer, "Source: {:?}", top_up.source)?;
+                writeln!(writer, "Target: {:?}", top_up.target)?;
+                writeln!(writer, "Target balance: {}", top_up.target_balance)?;
+            }
+            Self::NativeMultiTransfer(transfer) => {
+                writeln!(writer, "Transaction Kind : Native Multi-Recipient Transfer")?;
+                writeln!(writer, "Coin: {:?}", transfer.coin)?;
+                for (recipient, amount) in &transfer.recipients {
+                    writeln!(writer, "Recipient: {recipient}, Amount: {amount}")?;
+                }
+            }
+            Self::NativeMerge(merge) => {
+                writeln!(writer, "Transaction Kind : Native Merge")?;
+                writeln!(writer, "Primary coin: {:?}", merge.primary_coin)?;
+                for coin in &merge.coins_to_merge {
+                    writeln!(writer, "Merged coin: {:?}", coin)?;
+                }
+            }
+            Self::NativeTransferWhole(transfer) => {
+                writeln!(writer, "Transaction Kind : Native Whole-Coin Transfer")?;
+                writeln!(writer, "Coin: {:?}", transfer.coin)?;
+                writeln!(writer, "Recipient: {}", transfer.recipient)?;
+            }
+            Self::NativeTransferWithChange(transfer) => {
+                writeln!(writer, "Transaction Kind : Native Transfer With Change")?;
+                writeln!(writer, "Coin: {:?}", transfer.coin)?;
+                writeln!(writer, "Recipient: {}", transfer.recipient)?;
+                writeln!(writer, "Amount: {}", transfer.amount)?;
+                writeln!(writer, "Change recipient: {}", transfer.change_recipient)?;
+            }
+            Self::NativeSweep(sweep) => {
+                writeln!(writer, "Transaction Kind : Native Sweep")?;
+                for coin in &sweep.coins {
+                    writeln!(writer, "Coin: {:?}", coin)?;
+                }
+                writeln!(writer, "Recipient: {}", sweep.recipient)?;
+            }
+            Self::NativeTransferAfterEpoch(transfer) => {
+                writeln!(writer, "Transaction Kind : Native Transfer After Epoch")?;
+                writeln!(writer, "Coin: {:?}", transfer.coin)?;
+                writeln!(writer, "Recipient: {}", transfer.recipient)?;
+                writeln!(writer, "Amount: {}", transfer.amount)?;
+                writeln!(writer, "Not before epoch: {}", transfer.not_before_epoch)?;
+            }
+            Self::NativeSplitEqual(split) => {
+                writeln!(writer, "Transaction Kind : Native Split Equal")?;
+                writeln!(writer, "Coin: {:?}", split.coin)?;
+                writeln!(writer, "Parts: {}", split.parts)?;
+                writeln!(writer, "Recipient: {}", split.recipient)?;
+            }
+            Self::NativeTransferInto(transfer) => {
+                writeln!(writer, "Transaction Kind : Native Transfer Into")?;
+                writeln!(writer, "Coin: {:?}", transfer.coin)?;
+                writeln!(writer, "Recipient: {}", transfer.recipient)?;
+                writeln!(writer, "Recipient coin: {:?}", transfer.recipient_coin)?;
+                writeln!(writer, "Amount: {}", transfer.amount)?;
+            }
+            Self::NativeTransferWithConsent(transfer) => {
+                writeln!(writer, "Transaction Kind : Native Transfer With Consent")?;
+                writeln!(writer, "Coin: {:?}", transfer.coin)?;
+                writeln!(writer, "Recipient: {}", transfer.recipient)?;
+                writeln!(writer, "Amount: {}", transfer.amount)?;
+            }
         }
         write!(f, "{}", writer)
     }
 }
 
+// The sender owns the source coin, so a transfer back to them only splits the coin. Change sent
+// back to the sender is a plain `NativeTransfer`, which `new_native_transfer_with_change` builds.
+fn check_native_transfer_to_self(kind: &TransactionKind, sender: SuiAddress) -> UserInputResult {
+    let recipients = match kind {
+        TransactionKind::NativeTransfer(transfer)
+        | TransactionKind::NativeTransferWithConsent(transfer) => vec![transfer.recipient],
+        TransactionKind::NativeTransferAfterEpoch(transfer) => vec![transfer.recipient],
+        TransactionKind::NativeTransferInto(transfer) => vec![transfer.recipient],
+        TransactionKind::NativeTransferWithChange(transfer) => {
+            vec![transfer.recipient, transfer.change_recipient]
+        }
+        _ => return Ok(()),
+    };
+    for recipient in recipients {
+        fp_ensure!(
+            recipient != sender,
+            UserInputError::NativeTransferToSelf { recipient }
+        );
+    }
+    Ok(())
+}
+
+fn check_min_native_transfer_amount(amount: u64, config: &ProtocolConfig) -> UserInputResult {
+    if let Some(minimum) = config.min_native_transfer_amount_as_option() {
+        fp_ensure!(
+            amount >= minimum,
+            UserInputError::NativeTransferBelowMinimum { amount, minimum }
+        );
+    }
+    Ok(())
+}
+
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
 pub struct GasData {
     pub payment: Vec<ObjectRef>,
@@ -2064,15 +2565,229 @@ impl TransactionData {
         recipient: SuiAddress,
         amount: u64,
     ) -> Self {
-        TransactionData::V1(TransactionDataV1 {
-            kind: TransactionKind::NativeTransfer(NativeTransfer {
+        Self::new_unmetered_native(
+            sender,
+            TransactionKind::NativeTransfer(NativeTransfer {
+                coin,
+                recipient,
+                amount,
+            }),
+        )
+    }
+
+    /// Create a native transfer that must be signed by both the sender and the recipient, so the
+    /// recipient explicitly consents to receiving the coin.
+    pub fn new_native_transfer_with_recipient_consent(
+        sender: SuiAddress,
+        coin: ObjectRef,
+        recipient: SuiAddress,
+        amount: u64,
+    ) -> Self {
+        Self::new_unmetered_native(
+            sender,
+            TransactionKind::NativeTransferWithConsent(NativeTransfer {
+                coin,
+                recipient,
+                amount,
+            }),
+        )
+    }
+
+    /// Create a native transfer that names `sponsor` as its gas owner, so the sponsor must co-sign
+    /// it. Native transfers charge no gas, so the sponsor pays nothing; the field records who
+    /// authorized the transfer.
+    pub fn new_native_transfer_sponsored(
+        sender: SuiAddress,
+        sponsor: SuiAddress,
+        coin: ObjectRef,
+        recipient: SuiAddress,
+        amount: u64,
+    ) -> Self {
+        let mut data = Self::new_native_transfer(sender, coin, recipient, amount);
+        data.gas_data_mut().owner = sponsor;
+        data
+    }
+
+    /// Create a native top-up that moves exactly enough from `source` into `recipient_coin`, which
+    /// must also be owned by the sender, to bring its balance up to `target_balance`.
+    pub fn new_native_transfer_topup(
+        sender: SuiAddress,
+        source: ObjectRef,
+        recipient_coin: ObjectRef,
+        target_balance: u64,
+    ) -> Self {
+        Self::new_unmetered_native(
+            sender,
+            TransactionKind::NativeTopUp(NativeTopUp {
+                source,
+                target: recipient_coin,
+                target_balance,
+            }),
+        )
+    }
+
+    /// Create a native transfer that deducts the sum of `recipients`' amounts from `coin` and
+    /// creates a new coin for each recipient, in order.
+    pub fn new_native_transfer_multi(
+        sender: SuiAddress,
+        coin: ObjectRef,
+        recipients: Vec<(SuiAddress, u64)>,
+    ) -> Self {
+        Self::new_unmetered_native(
+            sender,
+            TransactionKind::NativeMultiTransfer(NativeMultiTransfer { coin, recipients }),
+        )
+    }
+
+    /// Create a native merge that adds the balances of `coins_to_merge` to `primary_coin` and
+    /// deletes them. All coins must be owned by the sender.
+    pub fn new_native_merge(
+        sender: SuiAddress,
+        primary_coin: ObjectRef,
+        coins_to_merge: Vec<ObjectRef>,
+    ) -> Self {
+        Self::new_unmetered_native(
+            sender,
+            TransactionKind::NativeMerge(NativeMerge {
+                primary_coin,
+                coins_to_merge,
+            }),
+        )
+    }
+
+    /// Create a native transfer that hands `coin` to `recipient` whole, changing its owner in place
+    /// rather than creating a new coin. The coin must be owned by the sender.
+    pub fn new_native_transfer_whole(
+        sender: SuiAddress,
+        coin: ObjectRef,
+        recipient: SuiAddress,
+    ) -> Self {
+        Self::new_unmetered_native(
+            sender,
+            TransactionKind::NativeTransferWhole(NativeTransferWhole { coin, recipient }),
+        )
+    }
+
+    /// Create a native transfer of `amount` from `coin` to `recipient` that sends the rest of the
+    /// coin's balance to `change_recipient` and deletes the coin. When the change goes back to the
+    /// sender this is an ordinary `new_native_transfer`, which keeps the change in `coin`.
+    pub fn new_native_transfer_with_change(
+        sender: SuiAddress,
+        coin: ObjectRef,
+        recipient: SuiAddress,
+        amount: u64,
+        change_recipient: SuiAddress,
+    ) -> Self {
+        if change_recipient == sender {
+            return Self::new_native_transfer(sender, coin, recipient, amount);
+        }
+        Self::new_unmetered_native(
+            sender,
+            TransactionKind::NativeTransferWithChange(NativeTransferWithChange {
+                coin,
+                recipient,
+                amount,
+                change_recipient,
+            }),
+        )
+    }
+
+    /// Create a native sweep that hands every coin in `coins` to `recipient` whole, changing their
+    /// owners in place rather than creating new coins. The coins must be owned by the sender.
+    pub fn new_native_sweep(
+        sender: SuiAddress,
+        coins: Vec<ObjectRef>,
+        recipient: SuiAddress,
+    ) -> Self {
+        Self::new_unmetered_native(
+            sender,
+            TransactionKind::NativeSweep(NativeSweep { coins, recipient }),
+        )
+    }
+
+    /// Create a native transfer that validators refuse to sign before `not_before_epoch`. The
+    /// coin is not reserved in the meantime: it stays spendable until the transfer is signed.
+    pub fn new_native_transfer_after_epoch(
+        sender: SuiAddress,
+        coin: ObjectRef,
+        recipient: SuiAddress,
+        amount: u64,
+        not_before_epoch: EpochId,
+    ) -> Self {
+        Self::new_unmetered_native(
+            sender,
+            TransactionKind::NativeTransferAfterEpoch(NativeTransferAfterEpoch {
                 coin,
                 recipient,
                 amount,
+                not_before_epoch,
             }),
+        )
+    }
+
+    /// Create a native split that replaces `coin` with `parts` coins of equal value owned by
+    /// `recipient`. The last coin also takes the remainder, so together they hold the whole
+    /// balance. The coin must be owned by the sender and hold at least one MIST per part.
+    pub fn new_native_split_equal(
+        sender: SuiAddress,
+        coin: ObjectRef,
+        parts: u32,
+        recipient: SuiAddress,
+    ) -> Self {
+        Self::new_unmetered_native(
             sender,
-            // Native transfers are unmetered and don't charge gas, so no gas payment is needed.
-            // The coin is already included in the NativeTransfer input objects.
+            TransactionKind::NativeSplitEqual(NativeSplitEqual {
+                coin,
+                parts,
+                recipient,
+            }),
+        )
+    }
+
+    /// Checks a native transaction against every rule that depends only on the transaction and
+    /// `config`: the kind's amounts and limits, the recipients, and the sender. It reads no
+    /// storage or epoch state, so SDKs can run it before submission. Returns an error for
+    /// transactions that are not native.
+    pub fn validity_check_native_transfer(&self, config: &ProtocolConfig) -> UserInputResult {
+        let kind = self.kind();
+        if !kind.is_unmetered() {
+            return Err(UserInputError::Unsupported(format!(
+                "{} is not a native transaction",
+                kind.name()
+            )));
+        }
+        kind.native_validity_check(config)?;
+        check_native_transfer_to_self(kind, self.sender())
+    }
+
+    /// Create a native transfer that adds `amount` to `recipient_coin`, an existing coin owned by
+    /// `recipient`, instead of creating a new coin. Both coins show as mutated. Because the
+    /// recipient's coin is an input, the transaction must be signed by both the sender and
+    /// `recipient`.
+    pub fn new_native_transfer_into(
+        sender: SuiAddress,
+        coin: ObjectRef,
+        recipient: SuiAddress,
+        recipient_coin: ObjectRef,
+        amount: u64,
+    ) -> Self {
+        Self::new_unmetered_native(
+            sender,
+            TransactionKind::NativeTransferInto(NativeTransferInto {
+                coin,
+                recipient,
+                recipient_coin,
+                amount,
+            }),
+        )
+    }
+
+    fn new_unmetered_native(sender: SuiAddress, kind: TransactionKind) -> Self {
+        TransactionData::V1(TransactionDataV1 {
+            kind,
+            sender,
+            // Native transactions are unmetered and don't charge gas, so no gas payment is needed.
+            // The coins are already included in the transaction kind's input objects.
             // Set budget and price to reasonable values (minimum checks are skipped for unmetered txs)
             gas_data: GasData {
                 price: DEFAULT_VALIDATOR_GAS_PRICE, // Use default gas price to meet RGP requirement
@@ -2411,6 +3126,43 @@ impl TransactionData {
     pub fn digest(&self) -> TransactionDigest {
         TransactionDigest::new(default_hash(self))
     }
+
+    /// The ID of the coin a native transfer creates for its recipient. Like any other created
+    /// object, it is derived from the transaction digest and creation index, so it is known before
+    /// execution. `NativeTransfer`, `NativeTransferWithConsent`, `NativeTransferAfterEpoch` and
+    /// `NativeTransferWithChange` all create the recipient's coin first, at index 0. Returns `None`
+    /// for kinds that create no coin for a single recipient.
+    ///
+    /// Integrations that need to match the coin to an off-chain record should key the record on
+    /// this ID. The ID cannot be chosen by the sender: owned-object transactions may execute in a
+    /// different order on each validator, so two transfers naming the same ID would succeed on
+    /// some validators and fail on others.
+    pub fn native_transfer_created_coin_id(&self) -> Option<ObjectID> {
+        matches!(
+            self.kind(),
+            TransactionKind::NativeTransfer(_)
+                | TransactionKind::NativeTransferWithConsent(_)
+                | TransactionKind::NativeTransferAfterEpoch(_)
+                | TransactionKind::NativeTransferWithChange(_)
+        )
+        .then(|| ObjectID::derive_id(self.digest(), 0))
+    }
+
+    /// The IDs of the coins a multi-recipient native transfer creates, in the order of its
+    /// `recipients`: the coin for the `i`th recipient has creation index `i`. Effects list created
+    /// objects sorted by ID, not in this order, so use this to match coins to recipients. Returns
+    /// `None` for other transaction kinds.
+    pub fn native_multi_transfer_created_coin_ids(&self) -> Option<Vec<ObjectID>> {
+        let TransactionKind::NativeMultiTransfer(transfer) = self.kind() else {
+            return None;
+        };
+        let digest = self.digest();
+        Some(
+            (0..transfer.recipients.len() as u64)
+                .map(|index| ObjectID::derive_id(digest, index))
+                .collect(),
+        )
+    }
 }
 
 #[enum_dispatch]
@@ -2444,6 +3196,10 @@ pub trait TransactionDataAPI {
 
     fn move_calls(&self) -> Vec<(&ObjectID, &str, &str)>;
 
+    /// Returns the native transfer this transaction performs, if it is one, whether or not the
+    /// recipient must consent to it.
+    fn as_native_transfer(&self) -> Option<&NativeTransfer>;
+
     fn input_objects(&self) -> UserInputResult<Vec<InputObjectKind>>;
 
     fn shared_input_objects(&self) -> Vec<SharedInputObject>;
@@ -2530,6 +3286,16 @@ impl TransactionDataAPI for TransactionDataV1 {
         if self.gas_owner() != self.sender {
             signers.push(self.gas_owner());
         }
+        if let TransactionKind::NativeTransferWithConsent(transfer) = &self.kind
+            && !signers.contains(&transfer.recipient)
+        {
+            signers.push(transfer.recipient);
+        }
+        if let Some((_, recipient)) = self.kind.recipient_owned_coin()
+            && !signers.contains(&recipient)
+        {
+            signers.push(recipient);
+        }
         signers
     }
 
@@ -2561,6 +3327,14 @@ impl TransactionDataAPI for TransactionDataV1 {
         self.kind.move_calls()
     }
 
+    fn as_native_transfer(&self) -> Option<&NativeTransfer> {
+        match &self.kind {
+            TransactionKind::NativeTransfer(transfer)
+            | TransactionKind::NativeTransferWithConsent(transfer) => Some(transfer),
+            _ => None,
+        }
+    }
+
     fn input_objects(&self) -> UserInputResult<Vec<InputObjectKind>> {
         let mut inputs = self.kind.input_objects()?;
 
@@ -2854,6 +3628,7 @@ impl TransactionDataAPI for TransactionDataV1 {
     // may not be provided and created "on the fly"
     fn validity_check_no_gas_check(&self, config: &ProtocolConfig) -> UserInputResult {
         self.kind().validity_check(config)?;
+        check_native_transfer_to_self(&self.kind, self.sender)?;
         self.check_sponsorship()
     }
 
@@ -2872,7 +3647,12 @@ impl TransactionDataAPI for TransactionDataV1 {
         if self.gas_owner() == self.sender() {
             return Ok(());
         }
-        if matches!(&self.kind, TransactionKind::ProgrammableTransaction(_)) {
+        if matches!(
+            &self.kind,
+            TransactionKind::ProgrammableTransaction(_)
+                | TransactionKind::NativeTransfer(_)
+                | TransactionKind::NativeTransferWithConsent(_)
+        ) {
             return Ok(());
         }
         Err(UserInputError::UnsupportedSponsoredTransactionKind)
@@ -2899,7 +3679,17 @@ impl TransactionDataAPI for TransactionDataV1 {
             | TransactionKind::AuthenticatorStateUpdate(_)
             | TransactionKind::EndOfEpochTransaction(_)
             | TransactionKind::RandomnessStateUpdate(_)
-            | TransactionKind::NativeTransfer(_) => false,
+            | TransactionKind::NativeTransfer(_)
+            | TransactionKind::NativeTopUp(_)
+            | TransactionKind::NativeMultiTransfer(_)
+            | TransactionKind::NativeMerge(_)
+            | TransactionKind::NativeTransferWhole(_)
+            | TransactionKind::NativeTransferWithChange(_)
+            | TransactionKind::NativeSweep(_)
+            | TransactionKind::NativeTransferAfterEpoch(_)
+            | TransactionKind::NativeSplitEqual(_)
+            | TransactionKind::NativeTransferInto(_)
+            | TransactionKind::NativeTransferWithConsent(_) => false,
         }
     }
 
@@ -3228,6 +4018,20 @@ impl SenderSignedData {
             }
         }
 
+        // Deferred native transfers cannot be signed before their epoch
+        if let TransactionKind::NativeTransferAfterEpoch(transfer) = tx_data.kind() {
+            fp_ensure!(
+                context.epoch >= transfer.not_before_epoch,
+                SuiErrorKind::UserInputError {
+                    error: UserInputError::NativeTransferNotYetExecutable {
+                        not_before_epoch: transfer.not_before_epoch,
+                        current_epoch: context.epoch,
+                    }
+                }
+                .into()
+            );
+        }
+
         // Enforce overall transaction size limit.
         let tx_size = self.serialized_size()?;
         let max_tx_size_bytes = context.config.max_tx_size_bytes();
@@ -0,0 +1,785 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use shared_crypto::intent::{Intent, IntentMessage, IntentScope};
+use sui_protocol_config::{Chain, ProtocolConfig, ProtocolVersion};
+
+use super::{
+    LedgerSide, NativeTransferCoinSelection, NativeTransferSortKey, NativeTransferSummary,
+    explain_native_transfer_rejection, native_multi_transfer_ledger_rows,
+    select_native_transfer_coins,
+};
+use crate::base_types::{
+    ObjectID, ObjectRef, SequenceNumber, SuiAddress, TransactionDigest, random_object_ref,
+};
+use crate::crypto::{AccountKeyPair, Signature, get_key_pair};
+use crate::effects::TestEffectsBuilder;
+use crate::error::UserInputError;
+use crate::execution_status::{ExecutionFailureStatus, ExecutionStatus};
+use crate::gas_coin::GAS;
+use crate::programmable_transaction_builder::ProgrammableTransactionBuilder;
+use crate::signature::VerifyParams;
+use crate::transaction::{
+    FundsWithdrawalArg, Transaction, TransactionData, TransactionDataAPI, TransactionExpiration,
+    TransactionKind,
+};
+use crate::type_input::TypeInput;
+
+fn native_transfer(amount: u64) -> TransactionData {
+    TransactionData::new_native_transfer(
+        SuiAddress::random_for_testing_only(),
+        random_object_ref(),
+        SuiAddress::random_for_testing_only(),
+        amount,
+    )
+}
+
+fn native_transfer_config() -> ProtocolConfig {
+    // Protocol version 31 runs execution version 2, the only one with a native executor.
+    let mut config = ProtocolConfig::get_for_version(ProtocolVersion::new(31), Chain::Unknown);
+    config.set_native_transfer_enabled_for_testing(true);
+    config
+}
+
+fn explain(tx_data: &TransactionData) -> Option<String> {
+    explain_native_transfer_rejection(tx_data, &native_transfer_config())
+}
+
+#[test]
+fn test_explain_valid_transfer() {
+    assert_eq!(explain(&native_transfer(100)), None);
+}
+
+#[test]
+fn test_as_native_transfer() {
+    let tx_data = native_transfer(100);
+    let transfer = tx_data.as_native_transfer().unwrap();
+    assert_eq!(transfer.amount, 100);
+    assert_eq!(tx_data.required_signers().len(), 1);
+
+    // The kind survives a BCS round trip and is still classified as a native transfer.
+    let bytes = bcs::to_bytes(tx_data.kind()).unwrap();
+    let kind: TransactionKind = bcs::from_bytes(&bytes).unwrap();
+    assert_eq!(&kind, tx_data.kind());
+    let decoded: TransactionData = bcs::from_bytes(&bcs::to_bytes(&tx_data).unwrap()).unwrap();
+    assert_eq!(decoded.as_native_transfer(), Some(transfer));
+
+    let top_up = TransactionData::new_native_transfer_topup(
+        SuiAddress::random_for_testing_only(),
+        random_object_ref(),
+        random_object_ref(),
+        100,
+    );
+    assert_eq!(top_up.as_native_transfer(), None);
+}
+
+#[test]
+fn test_native_transfer_with_consent() {
+    let sender = SuiAddress::random_for_testing_only();
+    let recipient = SuiAddress::random_for_testing_only();
+    let tx_data = TransactionData::new_native_transfer_with_recipient_consent(
+        sender,
+        random_object_ref(),
+        recipient,
+        100,
+    );
+
+    // Consent has its own kind, so plain native transfers keep their wire layout.
+    assert!(matches!(
+        tx_data.kind(),
+        TransactionKind::NativeTransferWithConsent(_)
+    ));
+    assert_eq!(tx_data.as_native_transfer().unwrap().recipient, recipient);
+    assert_eq!(
+        Vec::from(tx_data.required_signers()),
+        vec![sender, recipient]
+    );
+    assert_eq!(tx_data.validity_check(&native_transfer_config()), Ok(()));
+}
+
+#[test]
+fn test_native_transfers_disabled() {
+    let tx_data = native_transfer(100);
+    let mut config = native_transfer_config();
+    config.set_native_transfer_enabled_for_testing(false);
+
+    assert_eq!(
+        tx_data.validity_check(&config),
+        Err(UserInputError::Unsupported(
+            "NativeTransfer is not supported".to_string()
+        ))
+    );
+    let explanation = explain_native_transfer_rejection(&tx_data, &config).unwrap();
+    assert!(
+        explanation.contains("native transfers are not enabled"),
+        "{explanation}"
+    );
+}
+
+#[test]
+fn test_native_transfers_enabled_on_devnet() {
+    let enabled = |version, chain| {
+        ProtocolConfig::get_for_version(ProtocolVersion::new(version), chain)
+            .native_transfer_enabled()
+    };
+    assert!(!enabled(31, Chain::Unknown));
+    assert!(!enabled(105, Chain::Unknown));
+    assert!(enabled(106, Chain::Unknown));
+    assert!(!enabled(106, Chain::Mainnet));
+    assert!(!enabled(106, Chain::Testnet));
+
+    // Version 106 runs execution version 3, which has no native executor.
+    let config = ProtocolConfig::get_for_version(ProtocolVersion::new(106), Chain::Unknown);
+    assert_eq!(
+        native_transfer(100).validity_check(&config),
+        Err(UserInputError::Unsupported(
+            "NativeTransfer requires execution version 2".to_string()
+        ))
+    );
+}
+
+#[test]
+fn test_native_transfers_require_execution_version_2() {
+    // Protocol version 20 runs execution version 1, and the max version runs execution version 3.
+    // Neither has a native executor.
+    for mut config in [
+        ProtocolConfig::get_for_version(ProtocolVersion::new(20), Chain::Unknown),
+        ProtocolConfig::get_for_max_version_UNSAFE(),
+    ] {
+        assert_ne!(config.execution_version_as_option(), Some(2));
+        config.set_native_transfer_enabled_for_testing(true);
+        assert_eq!(
+            native_transfer(100).validity_check(&config),
+            Err(UserInputError::Unsupported(
+                "NativeTransfer requires execution version 2".to_string()
+            ))
+        );
+        let explanation =
+            explain_native_transfer_rejection(&native_transfer(100), &config).unwrap();
+        assert!(
+            explanation.contains("does not run the native execution engine"),
+            "{explanation}"
+        );
+    }
+}
+
+#[test]
+fn test_explain_zero_amount() {
+    let explanation = explain(&native_transfer(0)).unwrap();
+    assert!(explanation.contains("amount 0"), "{explanation}");
+    assert!(explanation.contains("non-zero amount"), "{explanation}");
+}
+
+#[test]
+fn test_native_transfer_below_minimum() {
+    let mut config = native_transfer_config();
+    config.set_min_native_transfer_amount_for_testing(100);
+
+    let below = native_transfer(50);
+    assert_eq!(
+        below.validity_check(&config),
+        Err(UserInputError::NativeTransferBelowMinimum {
+            amount: 50,
+            minimum: 100
+        })
+    );
+    let explanation = explain_native_transfer_rejection(&below, &config).unwrap();
+    assert!(explanation.contains("below the minimum"), "{explanation}");
+
+    assert_eq!(native_transfer(100).validity_check(&config), Ok(()));
+
+    // Each recipient of a multi-recipient transfer must receive at least the minimum.
+    let multi = TransactionData::new_native_transfer_multi(
+        SuiAddress::random_for_testing_only(),
+        random_object_ref(),
+        vec![
+            (SuiAddress::random_for_testing_only(), 200),
+            (SuiAddress::random_for_testing_only(), 50),
+        ],
+    );
+    assert_eq!(
+        multi.validity_check(&config),
+        Err(UserInputError::NativeTransferBelowMinimum {
+            amount: 50,
+            minimum: 100
+        })
+    );
+}
+
+#[test]
+fn test_native_transfer_to_zero_address() {
+    let to_zero = TransactionData::new_native_transfer(
+        SuiAddress::random_for_testing_only(),
+        random_object_ref(),
+        SuiAddress::ZERO,
+        100,
+    );
+    let mut config = native_transfer_config();
+    config.set_reject_native_transfer_to_zero_address_for_testing(false);
+    assert_eq!(to_zero.validity_check(&config), Ok(()));
+
+    config.set_reject_native_transfer_to_zero_address_for_testing(true);
+    assert_eq!(
+        to_zero.validity_check(&config),
+        Err(UserInputError::NativeTransferToZeroAddress)
+    );
+    assert_eq!(native_transfer(100).validity_check(&config), Ok(()));
+
+    // The zero address is rejected as a change recipient too.
+    let change_to_zero = TransactionData::new_native_transfer_with_change(
+        SuiAddress::random_for_testing_only(),
+        random_object_ref(),
+        SuiAddress::random_for_testing_only(),
+        100,
+        SuiAddress::ZERO,
+    );
+    assert_eq!(
+        change_to_zero.validity_check(&config),
+        Err(UserInputError::NativeTransferToZeroAddress)
+    );
+}
+
+#[test]
+fn test_explain_transfer_to_self() {
+    let sender = SuiAddress::random_for_testing_only();
+    let tx_data = TransactionData::new_native_transfer(sender, random_object_ref(), sender, 100);
+    let explanation = explain(&tx_data).unwrap();
+    assert!(
+        explanation.contains(&format!("recipient {sender} is the sender")),
+        "{explanation}"
+    );
+}
+
+#[test]
+fn test_native_transfer_kinds_to_self() {
+    let sender = SuiAddress::random_for_testing_only();
+    let other = SuiAddress::random_for_testing_only();
+    let config = native_transfer_config();
+    let to_self = Err(UserInputError::NativeTransferToSelf { recipient: sender });
+
+    let after_epoch = TransactionData::new_native_transfer_after_epoch(
+        sender,
+        random_object_ref(),
+        sender,
+        100,
+        1,
+    );
+    assert_eq!(after_epoch.validity_check(&config), to_self);
+
+    let into = TransactionData::new_native_transfer_into(
+        sender,
+        random_object_ref(),
+        sender,
+        random_object_ref(),
+        100,
+    );
+    assert_eq!(into.validity_check(&config), to_self);
+
+    let with_change = TransactionData::new_native_transfer_with_change(
+        sender,
+        random_object_ref(),
+        sender,
+        100,
+        other,
+    );
+    assert_eq!(with_change.validity_check(&config), to_self);
+
+    // The constructor turns change for the sender into a plain transfer, so build this one by hand.
+    let mut change_to_self = TransactionData::new_native_transfer_with_change(
+        sender,
+        random_object_ref(),
+        other,
+        100,
+        SuiAddress::random_for_testing_only(),
+    );
+    let TransactionKind::NativeTransferWithChange(transfer) = change_to_self.kind_mut() else {
+        panic!("expected a transfer with change");
+    };
+    transfer.change_recipient = sender;
+    assert_eq!(change_to_self.validity_check(&config), to_self);
+}
+
+#[test]
+fn test_explain_kind_level_errors_of_other_native_kinds() {
+    let split = |parts| {
+        TransactionData::new_native_split_equal(
+            SuiAddress::random_for_testing_only(),
+            random_object_ref(),
+            parts,
+            SuiAddress::random_for_testing_only(),
+        )
+    };
+    // These fail kind-level rules with the same errors as the gas payment and expiration checks,
+    // so the explanation must not blame the gas payment or the expiration.
+    let config = native_transfer_config();
+    for tx_data in [
+        split(0),
+        split(config.max_num_new_move_object_ids() as u32 + 1),
+    ] {
+        let err = tx_data.kind().validity_check(&config).unwrap_err();
+        assert_eq!(explain(&tx_data), Some(err.to_string()));
+    }
+}
+
+#[test]
+fn test_explain_zero_top_up_target() {
+    let tx_data = TransactionData::new_native_transfer_topup(
+        SuiAddress::random_for_testing_only(),
+        random_object_ref(),
+        random_object_ref(),
+        0,
+    );
+    let explanation = explain(&tx_data).unwrap();
+    assert!(explanation.contains("target balance 0"), "{explanation}");
+}
+
+#[test]
+fn test_explain_sponsored_transfer() {
+    let sender = SuiAddress::random_for_testing_only();
+    let sponsor = SuiAddress::random_for_testing_only();
+    let tx_data = TransactionData::new_native_transfer_sponsored(
+        sender,
+        sponsor,
+        random_object_ref(),
+        SuiAddress::random_for_testing_only(),
+        100,
+    );
+    assert_eq!(tx_data.gas_owner(), sponsor);
+    assert_eq!(tx_data.required_signers().len(), 2);
+    assert_eq!(explain(&tx_data), None);
+
+    // Other native kinds still cannot be sponsored.
+    let mut tx_data = TransactionData::new_native_transfer_topup(
+        sender,
+        random_object_ref(),
+        random_object_ref(),
+        100,
+    );
+    tx_data.gas_data_mut().owner = sponsor;
+    let explanation = explain(&tx_data).unwrap();
+    assert!(
+        explanation.contains(&format!("gas owner {sponsor}")),
+        "{explanation}"
+    );
+    assert!(
+        explanation.contains("only native transfers can be sponsored"),
+        "{explanation}"
+    );
+}
+
+#[test]
+fn test_explain_unsupported_expiration() {
+    // Zero amount is also invalid, but expiration is checked first and must be what is reported.
+    let mut tx_data = native_transfer(0);
+    *tx_data.expiration_mut_for_testing() = TransactionExpiration::ValidDuring {
+        min_epoch: Some(0),
+        max_epoch: Some(1),
+        min_timestamp_seconds: None,
+        max_timestamp_seconds: None,
+        chain: Default::default(),
+        nonce: 0,
+    };
+
+    let explanation = explain(&tx_data).unwrap();
+    assert!(explanation.contains("ValidDuring"), "{explanation}");
+    assert!(
+        explanation.contains("min_epoch must equal max_epoch"),
+        "{explanation}"
+    );
+    assert!(!explanation.contains("amount 0"), "{explanation}");
+}
+
+#[test]
+fn test_explain_unsupported_withdrawal() {
+    // Funds withdrawals are not enabled at version 31. The expiration is fine and must not be
+    // blamed.
+    let mut ptb = ProgrammableTransactionBuilder::new();
+    ptb.funds_withdrawal(FundsWithdrawalArg::balance_from_sender(
+        100,
+        TypeInput::from(GAS::type_tag()),
+    ))
+    .unwrap();
+    let tx_data = TransactionData::new_programmable(
+        SuiAddress::random_for_testing_only(),
+        vec![random_object_ref()],
+        ptb.finish(),
+        1,
+        1,
+    );
+
+    let err = tx_data
+        .validity_check(&native_transfer_config())
+        .unwrap_err();
+    let explanation = explain(&tx_data).unwrap();
+    assert_eq!(explanation, err.to_string());
+    assert!(!explanation.contains("expiration"), "{explanation}");
+}
+
+#[test]
+fn test_ledger_rows_balance() {
+    let summary = NativeTransferSummary {
+        transaction_digest: TransactionDigest::random(),
+        sender: SuiAddress::random_for_testing_only(),
+        recipient: SuiAddress::random_for_testing_only(),
+        source_coin: ObjectID::random(),
+        source_remaining: 700,
+        created_coin: ObjectID::random(),
+        created_coin_version: SequenceNumber::from_u64(2),
+        recipient_amount: 300,
+        coin_type: GAS::type_tag(),
+        source_balances: None,
+    };
+
+    let rows = summary.ledger_rows();
+    let total = |side| {
+        rows.iter()
+            .filter(|row| row.side == side)
+            .map(|row| row.amount)
+            .sum::<u64>()
+    };
+    assert_eq!(total(LedgerSide::Debit), 300);
+    assert_eq!(total(LedgerSide::Credit), 300);
+
+    let [debit, credit] = rows.as_slice() else {
+        panic!("expected one debit and one credit, got {rows:?}");
+    };
+    assert_eq!(
+        (debit.owner, debit.coin),
+        (summary.sender, summary.source_coin)
+    );
+    assert_eq!(
+        (credit.owner, credit.coin),
+        (summary.recipient, summary.created_coin)
+    );
+    assert!(
+        rows.iter()
+            .all(|row| row.transaction_digest == summary.transaction_digest)
+    );
+}
+
+#[test]
+fn test_multi_transfer_ledger_rows_balance() {
+    let recipients = vec![
+        (SuiAddress::random_for_testing_only(), 300),
+        (SuiAddress::random_for_testing_only(), 200),
+        (SuiAddress::random_for_testing_only(), 100),
+    ];
+    let tx_data = TransactionData::new_native_transfer_multi(
+        SuiAddress::random_for_testing_only(),
+        random_object_ref(),
+        recipients.clone(),
+    );
+    let transaction = Transaction::from_data(tx_data.clone(), vec![]);
+    let effects = TestEffectsBuilder::new(transaction.data()).build();
+
+    let rows = native_multi_transfer_ledger_rows(&tx_data, &effects).unwrap();
+    let total = |side| {
+        rows.iter()
+            .filter(|row| row.side == side)
+            .map(|row| row.amount)
+            .sum::<u64>()
+    };
+    assert_eq!(total(LedgerSide::Debit), 600);
+    assert_eq!(total(LedgerSide::Credit), 600);
+
+    let [debit, credits @ ..] = rows.as_slice() else {
+        panic!("expected a debit, got {rows:?}");
+    };
+    let TransactionKind::NativeMultiTransfer(transfer) = tx_data.kind() else {
+        unreachable!();
+    };
+    assert_eq!(debit.side, LedgerSide::Debit);
+    assert_eq!(
+        (debit.owner, debit.coin),
+        (tx_data.sender(), transfer.coin.0)
+    );
+
+    // One credit per recipient, for the coin created for it.
+    let coin_ids = tx_data.native_multi_transfer_created_coin_ids().unwrap();
+    assert_eq!(credits.len(), recipients.len());
+    for ((credit, (recipient, amount)), coin) in credits.iter().zip(recipients).zip(coin_ids) {
+        assert_eq!(credit.side, LedgerSide::Credit);
+        assert_eq!(
+            (credit.owner, credit.coin, credit.amount),
+            (recipient, coin, amount)
+        );
+    }
+    assert!(
+        rows.iter()
+            .all(|row| row.transaction_digest == *transaction.digest())
+    );
+}
+
+#[test]
+fn test_sort_key_orders_outputs_of_one_transaction() {
+    // Outputs of one transaction order by output index, before the next transaction's.
+    let sorted = [(1, 0, 0), (1, 0, 1), (1, 0, 2), (1, 1, 0), (2, 0, 0)].map(
+        |(checkpoint, transaction_index, output_index)| NativeTransferSortKey {
+            checkpoint,
+            transaction_index,
+            output_index,
+        },
+    );
+    let mut keys = [sorted[4], sorted[2], sorted[0], sorted[3], sorted[1]];
+    keys.sort();
+    assert_eq!(keys, sorted);
+}
+
+#[test]
+fn test_multi_transfer_ledger_rows_skip_failed_and_other_kinds() {
+    let tx_data = TransactionData::new_native_transfer_multi(
+        SuiAddress::random_for_testing_only(),
+        random_object_ref(),
+        vec![(SuiAddress::random_for_testing_only(), 300)],
+    );
+    let transaction = Transaction::from_data(tx_data.clone(), vec![]);
+    let effects = TestEffectsBuilder::new(transaction.data())
+        .with_status(ExecutionStatus::new_failure(
+            ExecutionFailureStatus::InsufficientCoinBalance,
+            None,
+        ))
+        .build();
+    assert_eq!(native_multi_transfer_ledger_rows(&tx_data, &effects), None);
+
+    let tx_data = native_transfer(100);
+    let transaction = Transaction::from_data(tx_data.clone(), vec![]);
+    let effects = TestEffectsBuilder::new(transaction.data()).build();
+    assert_eq!(native_multi_transfer_ledger_rows(&tx_data, &effects), None);
+}
+
+fn coins(balances: &[u64]) -> Vec<(ObjectRef, u64)> {
+    balances
+        .iter()
+        .map(|balance| (random_object_ref(), *balance))
+        .collect()
+}
+
+#[test]
+fn test_select_coins_prefers_exact_fit() {
+    let coins = coins(&[500, 300, 1_000]);
+    assert_eq!(
+        select_native_transfer_coins(&coins, 300),
+        Some(NativeTransferCoinSelection::ExactFit(coins[1].0))
+    );
+}
+
+#[test]
+fn test_select_coins_smallest_sufficient() {
+    let coins = coins(&[5_000, 400, 800, 100]);
+    assert_eq!(
+        select_native_transfer_coins(&coins, 300),
+        Some(NativeTransferCoinSelection::SmallestSufficient(coins[1].0))
+    );
+}
+
+#[test]
+fn test_select_coins_aggregates_fewest_coins() {
+    let coins = coins(&[100, 400, 50, 300, 200]);
+    assert_eq!(
+        select_native_transfer_coins(&coins, 650),
+        Some(NativeTransferCoinSelection::Aggregate(vec![
+            coins[1].0, coins[3].0
+        ]))
+    );
+    assert_eq!(
+        select_native_transfer_coins(&coins, 1_050),
+        Some(NativeTransferCoinSelection::Aggregate(
+            [1, 3, 4, 0, 2].map(|i| coins[i].0).to_vec()
+        ))
+    );
+}
+
+#[test]
+fn test_select_coins_insufficient() {
+    let coins = coins(&[100, 200]);
+    assert_eq!(select_native_transfer_coins(&coins, 301), None);
+    assert_eq!(select_native_transfer_coins(&[], 1), None);
+    assert_eq!(select_native_transfer_coins(&coins, 0), None);
+}
+
+fn native_transfer_signed_with(intent: Intent) -> Transaction {
+    let (sender, sender_key): (_, AccountKeyPair) = get_key_pair();
+    let tx_data = TransactionData::new_native_transfer(
+        sender,
+        random_object_ref(),
+        SuiAddress::random_for_testing_only(),
+        100,
+    );
+    let signature = Signature::new_secure(&IntentMessage::new(intent, &tx_data), &sender_key);
+    Transaction::from_data(tx_data, vec![signature])
+}
+
+#[test]
+fn test_native_transfer_signed_with_transaction_intent() {
+    native_transfer_signed_with(Intent::sui_transaction())
+        .verify_signature_for_testing(0, &VerifyParams::default())
+        .unwrap();
+}
+
+#[test]
+fn test_native_transfer_signed_with_other_intent_rejected() {
+    for scope in [
+        IntentScope::PersonalMessage,
+        IntentScope::TransactionEffects,
+        IntentScope::SenderSignedTransaction,
+    ] {
+        native_transfer_signed_with(Intent::sui_app(scope))
+            .verify_signature_for_testing(0, &VerifyParams::default())
+            .unwrap_err();
+    }
+}
+
+#[test]
+fn test_created_coin_id_for_single_recipient_transfers() {
+    let tx_data = native_transfer(100);
+    assert_eq!(
+        tx_data.native_transfer_created_coin_id(),
+        Some(ObjectID::derive_id(tx_data.digest(), 0))
+    );
+
+    let top_up = TransactionData::new_native_transfer_topup(
+        SuiAddress::random_for_testing_only(),
+        random_object_ref(),
+        random_object_ref(),
+        100,
+    );
+    assert_eq!(top_up.native_transfer_created_coin_id(), None);
+
+    // Transfers that also create the recipient's coin first share the same ID.
+    let after_epoch = TransactionData::new_native_transfer_after_epoch(
+        SuiAddress::random_for_testing_only(),
+        random_object_ref(),
+        SuiAddress::random_for_testing_only(),
+        100,
+        1,
+    );
+    assert_eq!(
+        after_epoch.native_transfer_created_coin_id(),
+        Some(ObjectID::derive_id(after_epoch.digest(), 0))
+    );
+    let with_change = TransactionData::new_native_transfer_with_change(
+        SuiAddress::random_for_testing_only(),
+        random_object_ref(),
+        SuiAddress::random_for_testing_only(),
+        100,
+        SuiAddress::random_for_testing_only(),
+    );
+    assert_eq!(
+        with_change.native_transfer_created_coin_id(),
+        Some(ObjectID::derive_id(with_change.digest(), 0))
+    );
+}
+
+#[test]
+fn test_native_split_equal_parts() {
+    let split = |parts| {
+        TransactionData::new_native_split_equal(
+            SuiAddress::random_for_testing_only(),
+            random_object_ref(),
+            parts,
+            SuiAddress::random_for_testing_only(),
+        )
+    };
+    let config = native_transfer_config();
+    assert!(matches!(
+        split(0).validity_check(&config),
+        Err(UserInputError::Unsupported(_))
+    ));
+    assert_eq!(split(3).validity_check(&config), Ok(()));
+
+    let max_parts = config.max_num_new_move_object_ids() as u32;
+    assert_eq!(split(max_parts).validity_check(&config), Ok(()));
+    assert!(matches!(
+        split(max_parts + 1).validity_check(&config),
+        Err(UserInputError::SizeLimitExceeded { .. })
+    ));
+}
+
+#[test]
+fn test_validity_check_native_transfer() {
+    let mut config = native_transfer_config();
+    config.set_min_native_transfer_amount_for_testing(100);
+
+    assert_eq!(
+        native_transfer(100).validity_check_native_transfer(&config),
+        Ok(())
+    );
+    assert!(matches!(
+        native_transfer(0).validity_check_native_transfer(&config),
+        Err(UserInputError::Unsupported(_))
+    ));
+    assert_eq!(
+        native_transfer(50).validity_check_native_transfer(&config),
+        Err(UserInputError::NativeTransferBelowMinimum {
+            amount: 50,
+            minimum: 100
+        })
+    );
+
+    let sender = SuiAddress::random_for_testing_only();
+    let to_self = TransactionData::new_native_transfer(sender, random_object_ref(), sender, 100);
+    assert_eq!(
+        to_self.validity_check_native_transfer(&config),
+        Err(UserInputError::NativeTransferToSelf { recipient: sender })
+    );
+
+    // The pure check agrees with the full one on native transactions.
+    let mut disabled = config.clone();
+    disabled.set_native_transfer_enabled_for_testing(false);
+    assert_eq!(
+        native_transfer(100).validity_check_native_transfer(&disabled),
+        native_transfer(100).validity_check(&disabled)
+    );
+
+    let not_native = TransactionData::new_transfer_sui(
+        SuiAddress::random_for_testing_only(),
+        SuiAddress::random_for_testing_only(),
+        Some(100),
+        random_object_ref(),
+        1_000_000,
+        1_000,
+    );
+    assert!(matches!(
+        not_native.validity_check_native_transfer(&config),
+        Err(UserInputError::Unsupported(_))
+    ));
+}
+
+#[test]
+fn test_native_transfer_into_signers() {
+    let sender = SuiAddress::random_for_testing_only();
+    let recipient = SuiAddress::random_for_testing_only();
+    let recipient_coin = random_object_ref();
+    let transfer_into = |recipient, amount| {
+        TransactionData::new_native_transfer_into(
+            sender,
+            random_object_ref(),
+            recipient,
+            recipient_coin,
+            amount,
+        )
+    };
+
+    // The recipient's coin is an input, so the recipient must sign too.
+    let tx_data = transfer_into(recipient, 100);
+    assert_eq!(
+        Vec::from(tx_data.required_signers()),
+        vec![sender, recipient]
+    );
+    assert_eq!(
+        tx_data.kind().recipient_owned_coin(),
+        Some((recipient_coin.0, recipient))
+    );
+    assert_eq!(tx_data.validity_check(&native_transfer_config()), Ok(()));
+
+    // Crediting one of the sender's own coins needs no other signature.
+    assert_eq!(
+        Vec::from(transfer_into(sender, 100).required_signers()),
+        vec![sender]
+    );
+
+    assert!(matches!(
+        transfer_into(recipient, 0).validity_check(&native_transfer_config()),
+        Err(UserInputError::Unsupported(_))
+    ));
+}
@@ -1,8 +1,14 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::base_types::{ObjectID, ObjectRef, SequenceNumber};
-use crate::transaction::{ObjectArg, SharedObjectMutability};
+use crate::base_types::{ObjectID, ObjectRef, SequenceNumber, SuiAddress};
+use crate::digests::ObjectDigest;
+use crate::transaction::{
+    NativeMerge, NativeMultiTransfer, NativeSplitEqual, NativeSweep, NativeTopUp, NativeTransfer,
+    NativeTransferAfterEpoch, NativeTransferInto, NativeTransferWhole, NativeTransferWithChange,
+    ObjectArg, SharedObjectMutability, TransactionKind,
+};
+use sui_enum_compat_util::*;
 
 #[test]
 fn test_shared_object_backward_compatibility() {
@@ -67,3 +73,255 @@ fn test_shared_object_backward_compatibility() {
         _ => panic!("Expected SharedObject variant"),
     }
 }
+
+#[test]
+fn enforce_transaction_kind_order_test() {
+    let mut path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    path.extend(["tests", "staged", "transaction_kind.yaml"]);
+    check_enum_compat_order::<TransactionKind>(path);
+}
+
+fn object_ref_for_testing(byte: u8) -> ObjectRef {
+    (
+        ObjectID::new([byte; 32]),
+        SequenceNumber::from_u64(byte as u64),
+        ObjectDigest::new([byte; 32]),
+    )
+}
+
+fn object_ref_bytes(byte: u8) -> Vec<u8> {
+    [
+        vec![byte; 32],
+        (byte as u64).to_le_bytes().to_vec(),
+        // Digests are serialized as length-prefixed bytes.
+        vec![32],
+        vec![byte; 32],
+    ]
+    .concat()
+}
+
+#[test]
+fn test_native_transfer_bcs_layout() {
+    let kind = TransactionKind::NativeTransfer(NativeTransfer {
+        coin: object_ref_for_testing(1),
+        recipient: SuiAddress::from(ObjectID::new([2; 32])),
+        amount: 1_000,
+    });
+    let expected = [
+        vec![11],
+        object_ref_bytes(1),
+        vec![2; 32],
+        1_000u64.to_le_bytes().to_vec(),
+    ]
+    .concat();
+
+    let bytes = bcs::to_bytes(&kind).unwrap();
+    assert_eq!(bytes, expected);
+    assert_eq!(bcs::from_bytes::<TransactionKind>(&bytes).unwrap(), kind);
+}
+
+#[test]
+fn test_native_top_up_bcs_layout() {
+    let kind = TransactionKind::NativeTopUp(NativeTopUp {
+        source: object_ref_for_testing(1),
+        target: object_ref_for_testing(2),
+        target_balance: 5_000,
+    });
+    let expected = [
+        vec![12],
+        object_ref_bytes(1),
+        object_ref_bytes(2),
+        5_000u64.to_le_bytes().to_vec(),
+    ]
+    .concat();
+
+    let bytes = bcs::to_bytes(&kind).unwrap();
+    assert_eq!(bytes, expected);
+    assert_eq!(bcs::from_bytes::<TransactionKind>(&bytes).unwrap(), kind);
+}
+
+#[test]
+fn test_native_multi_transfer_bcs_layout() {
+    let kind = TransactionKind::NativeMultiTransfer(NativeMultiTransfer {
+        coin: object_ref_for_testing(1),
+        recipients: vec![
+            (SuiAddress::from(ObjectID::new([2; 32])), 1_000),
+            (SuiAddress::from(ObjectID::new([3; 32])), 2_000),
+        ],
+    });
+    let expected = [
+        vec![13],
+        object_ref_bytes(1),
+        vec![2],
+        vec![2; 32],
+        1_000u64.to_le_bytes().to_vec(),
+        vec![3; 32],
+        2_000u64.to_le_bytes().to_vec(),
+    ]
+    .concat();
+
+    let bytes = bcs::to_bytes(&kind).unwrap();
+    assert_eq!(bytes, expected);
+    assert_eq!(bcs::from_bytes::<TransactionKind>(&bytes).unwrap(), kind);
+}
+
+#[test]
+fn test_native_merge_bcs_layout() {
+    let kind = TransactionKind::NativeMerge(NativeMerge {
+        primary_coin: object_ref_for_testing(1),
+        coins_to_merge: vec![object_ref_for_testing(2), object_ref_for_testing(3)],
+    });
+    let expected = [
+        vec![14],
+        object_ref_bytes(1),
+        vec![2],
+        object_ref_bytes(2),
+        object_ref_bytes(3),
+    ]
+    .concat();
+
+    let bytes = bcs::to_bytes(&kind).unwrap();
+    assert_eq!(bytes, expected);
+    assert_eq!(bcs::from_bytes::<TransactionKind>(&bytes).unwrap(), kind);
+}
+
+#[test]
+fn test_native_transfer_whole_bcs_layout() {
+    let kind = TransactionKind::NativeTransferWhole(NativeTransferWhole {
+        coin: object_ref_for_testing(1),
+        recipient: SuiAddress::from(ObjectID::new([2; 32])),
+    });
+    let expected = [vec![15], object_ref_bytes(1), vec![2; 32]].concat();
+
+    let bytes = bcs::to_bytes(&kind).unwrap();
+    assert_eq!(bytes, expected);
+    assert_eq!(bcs::from_bytes::<TransactionKind>(&bytes).unwrap(), kind);
+}
+
+#[test]
+fn test_native_transfer_with_change_bcs_layout() {
+    let kind = TransactionKind::NativeTransferWithChange(NativeTransferWithChange {
+        coin: object_ref_for_testing(1),
+        recipient: SuiAddress::from(ObjectID::new([2; 32])),
+        amount: 1_000,
+        change_recipient: SuiAddress::from(ObjectID::new([3; 32])),
+    });
+    let expected = [
+        vec![16],
+        object_ref_bytes(1),
+        vec![2; 32],
+        1_000u64.to_le_bytes().to_vec(),
+        vec![3; 32],
+    ]
+    .concat();
+
+    let bytes = bcs::to_bytes(&kind).unwrap();
+    assert_eq!(bytes, expected);
+    assert_eq!(bcs::from_bytes::<TransactionKind>(&bytes).unwrap(), kind);
+}
+
+#[test]
+fn test_native_sweep_bcs_layout() {
+    let kind = TransactionKind::NativeSweep(NativeSweep {
+        coins: vec![object_ref_for_testing(1), object_ref_for_testing(3)],
+        recipient: SuiAddress::from(ObjectID::new([2; 32])),
+    });
+    let expected = [
+        vec![17],
+        // The coins are a length-prefixed sequence.
+        vec![2],
+        object_ref_bytes(1),
+        object_ref_bytes(3),
+        vec![2; 32],
+    ]
+    .concat();
+
+    let bytes = bcs::to_bytes(&kind).unwrap();
+    assert_eq!(bytes, expected);
+    assert_eq!(bcs::from_bytes::<TransactionKind>(&bytes).unwrap(), kind);
+}
+
+#[test]
+fn test_native_transfer_after_epoch_bcs_layout() {
+    let kind = TransactionKind::NativeTransferAfterEpoch(NativeTransferAfterEpoch {
+        coin: object_ref_for_testing(1),
+        recipient: SuiAddress::from(ObjectID::new([2; 32])),
+        amount: 1_000,
+        not_before_epoch: 7,
+    });
+    let expected = [
+        vec![18],
+        object_ref_bytes(1),
+        vec![2; 32],
+        1_000u64.to_le_bytes().to_vec(),
+        7u64.to_le_bytes().to_vec(),
+    ]
+    .concat();
+
+    let bytes = bcs::to_bytes(&kind).unwrap();
+    assert_eq!(bytes, expected);
+    assert_eq!(bcs::from_bytes::<TransactionKind>(&bytes).unwrap(), kind);
+}
+
+#[test]
+fn test_native_split_equal_bcs_layout() {
+    let kind = TransactionKind::NativeSplitEqual(NativeSplitEqual {
+        coin: object_ref_for_testing(1),
+        parts: 4,
+        recipient: SuiAddress::from(ObjectID::new([2; 32])),
+    });
+    let expected = [
+        vec![19],
+        object_ref_bytes(1),
+        4u32.to_le_bytes().to_vec(),
+        vec![2; 32],
+    ]
+    .concat();
+
+    let bytes = bcs::to_bytes(&kind).unwrap();
+    assert_eq!(bytes, expected);
+    assert_eq!(bcs::from_bytes::<TransactionKind>(&bytes).unwrap(), kind);
+}
+
+#[test]
+fn test_native_transfer_into_bcs_layout() {
+    let kind = TransactionKind::NativeTransferInto(NativeTransferInto {
+        coin: object_ref_for_testing(1),
+        recipient: SuiAddress::from(ObjectID::new([2; 32])),
+        recipient_coin: object_ref_for_testing(3),
+        amount: 500,
+    });
+    let expected = [
+        vec![20],
+        object_ref_bytes(1),
+        vec![2; 32],
+        object_ref_bytes(3),
+        500u64.to_le_bytes().to_vec(),
+    ]
+    .concat();
+
+    let bytes = bcs::to_bytes(&kind).unwrap();
+    assert_eq!(bytes, expected);
+    assert_eq!(bcs::from_bytes::<TransactionKind>(&bytes).unwrap(), kind);
+}
+
+#[test]
+fn test_native_transfer_with_consent_bcs_layout() {
+    // Same payload as `NativeTransfer`, under its own tag.
+    let kind = TransactionKind::NativeTransferWithConsent(NativeTransfer {
+        coin: object_ref_for_testing(1),
+        recipient: SuiAddress::from(ObjectID::new([2; 32])),
+        amount: 1_000,
+    });
+    let expected = [
+        vec![21],
+        object_ref_bytes(1),
+        vec![2; 32],
+        1_000u64.to_le_bytes().to_vec(),
+    ]
+    .concat();
+
+    let bytes = bcs::to_bytes(&kind).unwrap();
+    assert_eq!(bytes, expected);
+    assert_eq!(bcs::from_bytes::<TransactionKind>(&bytes).unwrap(), kind);
+}
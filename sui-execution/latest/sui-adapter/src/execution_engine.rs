@@ -849,6 +849,96 @@ mod checked {
                     vec![],
                 ));
             }
+            TransactionKind::NativeTopUp(_) => {
+                return Err((
+                    ExecutionError::new_with_source(
+                        ExecutionErrorKind::FeatureNotYetSupported,
+                        "NativeTopUp is not supported in latest execution layer",
+                    ),
+                    vec![],
+                ));
+            }
+            TransactionKind::NativeMultiTransfer(_) => {
+                return Err((
+                    ExecutionError::new_with_source(
+                        ExecutionErrorKind::FeatureNotYetSupported,
+                        "NativeMultiTransfer is not supported in latest execution layer",
+                    ),
+                    vec![],
+                ));
+            }
+            TransactionKind::NativeMerge(_) => {
+                return Err((
+                    ExecutionError::new_with_source(
+                        ExecutionErrorKind::FeatureNotYetSupported,
+                        "NativeMerge is not supported in latest execution layer",
+                    ),
+                    vec![],
+                ));
+            }
+            TransactionKind::NativeTransferWhole(_) => {
+                return Err((
+                    ExecutionError::new_with_source(
+                        ExecutionErrorKind::FeatureNotYetSupported,
+                        "NativeTransferWhole is not supported in latest execution layer",
+                    ),
+                    vec![],
+                ));
+            }
+            TransactionKind::NativeTransferWithChange(_) => {
+                return Err((
+                    ExecutionError::new_with_source(
+                        ExecutionErrorKind::FeatureNotYetSupported,
+                        "NativeTransferWithChange is not supported in latest execution layer",
+                    ),
+                    vec![],
+                ));
+            }
+            TransactionKind::NativeSweep(_) => {
+                return Err((
+                    ExecutionError::new_with_source(
+                        ExecutionErrorKind::FeatureNotYetSupported,
+                        "NativeSweep is not supported in latest execution layer",
+                    ),
+                    vec![],
+                ));
+            }
+            TransactionKind::NativeTransferAfterEpoch(_) => {
+                return Err((
+                    ExecutionError::new_with_source(
+                        ExecutionErrorKind::FeatureNotYetSupported,
+                        "NativeTransferAfterEpoch is not supported in latest execution layer",
+                    ),
+                    vec![],
+                ));
+            }
+            TransactionKind::NativeSplitEqual(_) => {
+                return Err((
+                    ExecutionError::new_with_source(
+                        ExecutionErrorKind::FeatureNotYetSupported,
+                        "NativeSplitEqual is not supported in latest execution layer",
+                    ),
+                    vec![],
+                ));
+            }
+            TransactionKind::NativeTransferInto(_) => {
+                return Err((
+                    ExecutionError::new_with_source(
+                        ExecutionErrorKind::FeatureNotYetSupported,
+                        "NativeTransferInto is not supported in latest execution layer",
+                    ),
+                    vec![],
+                ));
+            }
+            TransactionKind::NativeTransferWithConsent(_) => {
+                return Err((
+                    ExecutionError::new_with_source(
+                        ExecutionErrorKind::FeatureNotYetSupported,
+                        "NativeTransferWithConsent is not supported in latest execution layer",
+                    ),
+                    vec![],
+                ));
+            }
         }?;
         temporary_store
             .check_execution_results_consistency()
@@ -501,6 +501,36 @@ mod checked {
             TransactionKind::NativeTransfer(_) => {
                 panic!("NativeTransfer is not supported in execution layer v0");
             }
+            TransactionKind::NativeTopUp(_) => {
+                panic!("NativeTopUp is not supported in execution layer v0");
+            }
+            TransactionKind::NativeMultiTransfer(_) => {
+                panic!("NativeMultiTransfer is not supported in execution layer v0");
+            }
+            TransactionKind::NativeMerge(_) => {
+                panic!("NativeMerge is not supported in execution layer v0");
+            }
+            TransactionKind::NativeTransferWhole(_) => {
+                panic!("NativeTransferWhole is not supported in execution layer v0");
+            }
+            TransactionKind::NativeTransferWithChange(_) => {
+                panic!("NativeTransferWithChange is not supported in execution layer v0");
+            }
+            TransactionKind::NativeSweep(_) => {
+                panic!("NativeSweep is not supported in execution layer v0");
+            }
+            TransactionKind::NativeTransferAfterEpoch(_) => {
+                panic!("NativeTransferAfterEpoch is not supported in execution layer v0");
+            }
+            TransactionKind::NativeSplitEqual(_) => {
+                panic!("NativeSplitEqual is not supported in execution layer v0");
+            }
+            TransactionKind::NativeTransferInto(_) => {
+                panic!("NativeTransferInto is not supported in execution layer v0");
+            }
+            TransactionKind::NativeTransferWithConsent(_) => {
+                panic!("NativeTransferWithConsent is not supported in execution layer v0");
+            }
         }
     }
 
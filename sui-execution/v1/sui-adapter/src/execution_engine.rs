@@ -675,6 +675,36 @@ mod checked {
             TransactionKind::NativeTransfer(_) => {
                 panic!("NativeTransfer is not supported in execution layer v1");
             }
+            TransactionKind::NativeTopUp(_) => {
+                panic!("NativeTopUp is not supported in execution layer v1");
+            }
+            TransactionKind::NativeMultiTransfer(_) => {
+                panic!("NativeMultiTransfer is not supported in execution layer v1");
+            }
+            TransactionKind::NativeMerge(_) => {
+                panic!("NativeMerge is not supported in execution layer v1");
+            }
+            TransactionKind::NativeTransferWhole(_) => {
+                panic!("NativeTransferWhole is not supported in execution layer v1");
+            }
+            TransactionKind::NativeTransferWithChange(_) => {
+                panic!("NativeTransferWithChange is not supported in execution layer v1");
+            }
+            TransactionKind::NativeSweep(_) => {
+                panic!("NativeSweep is not supported in execution layer v1");
+            }
+            TransactionKind::NativeTransferAfterEpoch(_) => {
+                panic!("NativeTransferAfterEpoch is not supported in execution layer v1");
+            }
+            TransactionKind::NativeSplitEqual(_) => {
+                panic!("NativeSplitEqual is not supported in execution layer v1");
+            }
+            TransactionKind::NativeTransferInto(_) => {
+                panic!("NativeTransferInto is not supported in execution layer v1");
+            }
+            TransactionKind::NativeTransferWithConsent(_) => {
+                panic!("NativeTransferWithConsent is not supported in execution layer v1");
+            }
         }?;
         temporary_store.check_execution_results_consistency()?;
         Ok(result)
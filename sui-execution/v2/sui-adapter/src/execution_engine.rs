@@ -50,8 +50,10 @@ mod checked {
     use sui_types::sui_system_state::{AdvanceEpochParams, ADVANCE_EPOCH_SAFE_MODE_FUNCTION_NAME};
     use sui_types::transaction::{
         Argument, AuthenticatorStateExpire, AuthenticatorStateUpdate, CallArg, ChangeEpoch,
-        Command, EndOfEpochTransactionKind, GenesisTransaction, NativeTransfer, ObjectArg,
-        ProgrammableTransaction, TransactionKind,
+        Command, EndOfEpochTransactionKind, GenesisTransaction, NativeMerge, NativeMultiTransfer,
+        NativeSplitEqual, NativeSweep, NativeTopUp, NativeTransfer, NativeTransferInto,
+        NativeTransferWhole, NativeTransferWithChange, ObjectArg, ProgrammableTransaction,
+        TransactionKind,
     };
     use sui_types::transaction::{CheckedInputObjects, RandomnessStateUpdate};
     use sui_types::{
@@ -710,8 +712,58 @@ mod checked {
             TransactionKind::ProgrammableSystemTransaction(_) => {
                 panic!("ProgrammableSystemTransaction should not exist in execution layer v2");
             }
-            TransactionKind::NativeTransfer(transfer) => {
-                execute_native_transfer(temporary_store, transfer, tx_ctx)?;
+            // The recipient's consent is its signature, which is verified before execution
+            TransactionKind::NativeTransfer(transfer)
+            | TransactionKind::NativeTransferWithConsent(transfer) => {
+                execute_native_transfer(temporary_store, transfer, tx_ctx, protocol_config)?;
+                Ok(Mode::empty_results())
+            }
+            TransactionKind::NativeTopUp(top_up) => {
+                execute_native_top_up(temporary_store, top_up, tx_ctx, protocol_config)?;
+                Ok(Mode::empty_results())
+            }
+            TransactionKind::NativeMultiTransfer(transfer) => {
+                execute_native_multi_transfer(temporary_store, transfer, tx_ctx, protocol_config)?;
+                Ok(Mode::empty_results())
+            }
+            TransactionKind::NativeMerge(merge) => {
+                execute_native_merge(temporary_store, merge, tx_ctx)?;
+                Ok(Mode::empty_results())
+            }
+            TransactionKind::NativeTransferWhole(transfer) => {
+                execute_native_transfer_whole(temporary_store, transfer, tx_ctx, protocol_config)?;
+                Ok(Mode::empty_results())
+            }
+            TransactionKind::NativeTransferWithChange(transfer) => {
+                execute_native_transfer_with_change(
+                    temporary_store,
+                    transfer,
+                    tx_ctx,
+                    protocol_config,
+                )?;
+                Ok(Mode::empty_results())
+            }
+            TransactionKind::NativeSweep(sweep) => {
+                execute_native_sweep(temporary_store, sweep, tx_ctx, protocol_config)?;
+                Ok(Mode::empty_results())
+            }
+            TransactionKind::NativeTransferAfterEpoch(transfer) => {
+                // Validators refuse to sign it before `not_before_epoch`, and certificates only
+                // execute in the epoch they were signed in, so it runs as a plain transfer
+                let transfer = NativeTransfer {
+                    coin: transfer.coin,
+                    recipient: transfer.recipient,
+                    amount: transfer.amount,
+                };
+                execute_native_transfer(temporary_store, transfer, tx_ctx, protocol_config)?;
+                Ok(Mode::empty_results())
+            }
+            TransactionKind::NativeSplitEqual(split) => {
+                execute_native_split_equal(temporary_store, split, tx_ctx, protocol_config)?;
+                Ok(Mode::empty_results())
+            }
+            TransactionKind::NativeTransferInto(transfer) => {
+                execute_native_transfer_into(temporary_store, transfer, tx_ctx, protocol_config)?;
                 Ok(Mode::empty_results())
             }
         }?;
@@ -1159,49 +1211,257 @@ mod checked {
         temporary_store: &mut TemporaryStore<'_>,
         transfer: NativeTransfer,
         tx_ctx: &mut TxContext,
+        protocol_config: &ProtocolConfig,
+    ) -> Result<(), ExecutionError> {
+        use sui_types::base_types::ObjectID;
+        use sui_types::object::{MoveObject, Object, Owner};
+
+        // 1-3. Read the input coin, owned by the transaction sender, and extract its balance
+        let (coin_obj, mut coin) =
+            load_sender_gas_coin(temporary_store, &transfer.coin.0, tx_ctx, protocol_config)?;
+
+        // 4. Verify sufficient balance
+        let Some(remainder) = coin.value().checked_sub(transfer.amount) else {
+            return Err(insufficient_coin_balance(
+                coin.value(),
+                transfer.amount,
+                "Insufficient coin balance",
+                protocol_config,
+            ));
+        };
+
+        // 5. Reject partial transfers that would leave dust behind in the source coin
+        if let Some(threshold) = protocol_config.native_transfer_dust_threshold_as_option() {
+            if remainder != 0 && remainder < threshold {
+                return Err(ExecutionError::new_with_source(
+                    ExecutionErrorKind::NativeTransferDustRemainder {
+                        remainder,
+                        threshold,
+                    },
+                    "Remaining coin balance is below the dust threshold",
+                ));
+            }
+        }
+
+        // 6. Deduct amount from source coin
+        coin.0.balance.withdraw(transfer.amount).map_err(|e| {
+            ExecutionError::new_with_source(
+                ExecutionErrorKind::InvalidGasObject,
+                format!("Failed to withdraw: {}", e),
+            )
+        })?;
+
+        // 7. Update the source coin object
+        // Use the current version - it will be updated by the temporary store's lamport timestamp
+        let updated_coin_obj = Object::new_move(
+            MoveObject::new_gas_coin(coin_obj.version(), transfer.coin.0, coin.value()),
+            coin_obj.owner.clone(),
+            tx_ctx.digest(),
+        );
+        temporary_store.mutate_input_object(updated_coin_obj);
+
+        // 8. Create new coin for recipient. Its version is a placeholder: like every written
+        // object, it is assigned the transaction's lamport version when effects are computed
+        let new_coin_id = ObjectID::from(tx_ctx.fresh_id());
+        let new_coin_obj = Object::new_move(
+            MoveObject::new_gas_coin(
+                sui_types::base_types::SequenceNumber::MIN,
+                new_coin_id,
+                transfer.amount,
+            ),
+            Owner::AddressOwner(transfer.recipient),
+            tx_ctx.digest(),
+        );
+        temporary_store.create_object(new_coin_obj);
+
+        Ok(())
+    }
+
+    /// Execute a multi-recipient native transfer without Move VM and without gas charging. Either
+    /// every recipient coin is created or, on failure, none are.
+    ///
+    /// Recipient coins are created in the order of `transfer.recipients`, so the coin for the
+    /// `i`th recipient always has creation index `i` and its ID depends only on the transaction
+    /// digest. The effects themselves list created objects sorted by ID, which is the same on
+    /// every node.
+    fn execute_native_multi_transfer(
+        temporary_store: &mut TemporaryStore<'_>,
+        transfer: NativeMultiTransfer,
+        tx_ctx: &mut TxContext,
+        protocol_config: &ProtocolConfig,
     ) -> Result<(), ExecutionError> {
         use sui_types::base_types::ObjectID;
-        use sui_types::gas_coin::GasCoin;
         use sui_types::object::{MoveObject, Object, Owner};
 
-        // 1. Read the input coin object
-        let coin_obj = temporary_store
-            .read_object(&transfer.coin.0)
+        let (coin_obj, mut coin) =
+            load_sender_gas_coin(temporary_store, &transfer.coin.0, tx_ctx, protocol_config)?;
+
+        let total = transfer
+            .recipients
+            .iter()
+            .try_fold(0u64, |total, (_, amount)| total.checked_add(*amount))
             .ok_or_else(|| {
                 ExecutionError::new_with_source(
-                    ExecutionErrorKind::InvalidGasObject,
-                    "Coin object not found",
+                    ExecutionErrorKind::CoinBalanceOverflow,
+                    "Total transfer amount overflows",
                 )
             })?;
+        let Some(remainder) = coin.value().checked_sub(total) else {
+            return Err(insufficient_coin_balance(
+                coin.value(),
+                total,
+                "Insufficient coin balance",
+                protocol_config,
+            ));
+        };
 
-        // 2. Verify ownership (must be owned by transaction sender)
-        match &coin_obj.owner {
-            Owner::AddressOwner(owner) if *owner == tx_ctx.sender() => {}
-            _ => {
+        if let Some(threshold) = protocol_config.native_transfer_dust_threshold_as_option() {
+            if remainder != 0 && remainder < threshold {
                 return Err(ExecutionError::new_with_source(
-                    ExecutionErrorKind::InvalidGasObject,
-                    "Coin must be owned by transaction sender",
-                ))
+                    ExecutionErrorKind::NativeTransferDustRemainder {
+                        remainder,
+                        threshold,
+                    },
+                    "Remaining coin balance is below the dust threshold",
+                ));
             }
         }
 
-        // 3. Verify it's a coin and extract balance
-        let mut coin = GasCoin::try_from(coin_obj).map_err(|e| {
+        let new_coin_ids: Vec<_> = transfer
+            .recipients
+            .iter()
+            .map(|_| ObjectID::from(tx_ctx.fresh_id()))
+            .collect();
+
+        coin.0.balance.withdraw(total).map_err(|e| {
             ExecutionError::new_with_source(
                 ExecutionErrorKind::InvalidGasObject,
-                format!("Failed to convert to gas coin: {}", e),
+                format!("Failed to withdraw: {}", e),
             )
         })?;
+        let updated_coin_obj = Object::new_move(
+            MoveObject::new_gas_coin(coin_obj.version(), transfer.coin.0, coin.value()),
+            coin_obj.owner.clone(),
+            tx_ctx.digest(),
+        );
+        temporary_store.mutate_input_object(updated_coin_obj);
 
-        // 4. Verify sufficient balance
-        if coin.value() < transfer.amount {
-            return Err(ExecutionError::new_with_source(
-                ExecutionErrorKind::InsufficientCoinBalance,
+        for ((recipient, amount), new_coin_id) in transfer.recipients.into_iter().zip(new_coin_ids)
+        {
+            let new_coin_obj = Object::new_move(
+                MoveObject::new_gas_coin(
+                    sui_types::base_types::SequenceNumber::MIN,
+                    new_coin_id,
+                    amount,
+                ),
+                Owner::AddressOwner(recipient),
+                tx_ctx.digest(),
+            );
+            temporary_store.create_object(new_coin_obj);
+        }
+
+        Ok(())
+    }
+
+    /// Execute a native split without Move VM and without gas charging. The source coin is
+    /// deleted and its balance divided between `parts` new coins for the recipient, the last of
+    /// which also takes the remainder.
+    fn execute_native_split_equal(
+        temporary_store: &mut TemporaryStore<'_>,
+        split: NativeSplitEqual,
+        tx_ctx: &mut TxContext,
+        protocol_config: &ProtocolConfig,
+    ) -> Result<(), ExecutionError> {
+        use sui_types::base_types::ObjectID;
+        use sui_types::object::{MoveObject, Object, Owner};
+
+        let (_, coin) =
+            load_sender_gas_coin(temporary_store, &split.coin.0, tx_ctx, protocol_config)?;
+
+        // Every part must hold at least one MIST
+        let parts = u64::from(split.parts);
+        if coin.value() < parts {
+            return Err(insufficient_coin_balance(
+                coin.value(),
+                parts,
+                "Coin balance is smaller than the number of parts",
+                protocol_config,
+            ));
+        }
+        let share = coin.value() / parts;
+        let remainder = coin.value() % parts;
+
+        let new_coin_ids: Vec<_> = (0..split.parts)
+            .map(|_| ObjectID::from(tx_ctx.fresh_id()))
+            .collect();
+
+        temporary_store.delete_input_object(&split.coin.0);
+
+        let last = new_coin_ids.len() - 1;
+        for (i, new_coin_id) in new_coin_ids.into_iter().enumerate() {
+            let amount = if i == last { share + remainder } else { share };
+            let new_coin_obj = Object::new_move(
+                MoveObject::new_gas_coin(
+                    sui_types::base_types::SequenceNumber::MIN,
+                    new_coin_id,
+                    amount,
+                ),
+                Owner::AddressOwner(split.recipient),
+                tx_ctx.digest(),
+            );
+            temporary_store.create_object(new_coin_obj);
+        }
+
+        Ok(())
+    }
+
+    /// Execute a native transfer into an existing coin of the recipient without Move VM and
+    /// without gas charging. Both coins are mutated and nothing is created.
+    fn execute_native_transfer_into(
+        temporary_store: &mut TemporaryStore<'_>,
+        transfer: NativeTransferInto,
+        tx_ctx: &TxContext,
+        protocol_config: &ProtocolConfig,
+    ) -> Result<(), ExecutionError> {
+        use sui_types::object::{MoveObject, Object};
+
+        let (coin_obj, mut coin) =
+            load_sender_gas_coin(temporary_store, &transfer.coin.0, tx_ctx, protocol_config)?;
+        let (recipient_coin_obj, recipient_coin) = load_gas_coin_owned_by(
+            temporary_store,
+            &transfer.recipient_coin.0,
+            transfer.recipient,
+            protocol_config,
+        )?;
+
+        let Some(remainder) = coin.value().checked_sub(transfer.amount) else {
+            return Err(insufficient_coin_balance(
+                coin.value(),
+                transfer.amount,
                 "Insufficient coin balance",
+                protocol_config,
             ));
+        };
+
+        if let Some(threshold) = protocol_config.native_transfer_dust_threshold_as_option() {
+            if remainder != 0 && remainder < threshold {
+                return Err(ExecutionError::new_with_source(
+                    ExecutionErrorKind::NativeTransferDustRemainder {
+                        remainder,
+                        threshold,
+                    },
+                    "Remaining coin balance is below the dust threshold",
+                ));
+            }
         }
 
-        // 5. Deduct amount from source coin
+        let Some(recipient_balance) = recipient_coin.value().checked_add(transfer.amount) else {
+            return Err(ExecutionError::new_with_source(
+                ExecutionErrorKind::CoinBalanceOverflow,
+                "Recipient coin balance overflows",
+            ));
+        };
+
         coin.0.balance.withdraw(transfer.amount).map_err(|e| {
             ExecutionError::new_with_source(
                 ExecutionErrorKind::InvalidGasObject,
@@ -1209,31 +1469,325 @@ mod checked {
             )
         })?;
 
-        // 6. Update the source coin object
-        // Use the current version - it will be updated by the temporary store's lamport timestamp
         let updated_coin_obj = Object::new_move(
             MoveObject::new_gas_coin(coin_obj.version(), transfer.coin.0, coin.value()),
             coin_obj.owner.clone(),
             tx_ctx.digest(),
         );
-        temporary_store.mutate_input_object(updated_coin_obj);
-
-        // 7. Create new coin for recipient
-        let new_coin_id = ObjectID::from(tx_ctx.fresh_id());
-        let new_coin_obj = Object::new_move(
+        let updated_recipient_coin_obj = Object::new_move(
             MoveObject::new_gas_coin(
-                sui_types::base_types::SequenceNumber::MIN,
-                new_coin_id,
-                transfer.amount,
+                recipient_coin_obj.version(),
+                transfer.recipient_coin.0,
+                recipient_balance,
             ),
+            recipient_coin_obj.owner.clone(),
+            tx_ctx.digest(),
+        );
+        temporary_store.mutate_input_object(updated_coin_obj);
+        temporary_store.mutate_input_object(updated_recipient_coin_obj);
+
+        Ok(())
+    }
+
+    /// Execute a native top-up transaction without Move VM and without gas charging
+    fn execute_native_top_up(
+        temporary_store: &mut TemporaryStore<'_>,
+        top_up: NativeTopUp,
+        tx_ctx: &TxContext,
+        protocol_config: &ProtocolConfig,
+    ) -> Result<(), ExecutionError> {
+        use sui_types::object::{MoveObject, Object};
+
+        let (source_obj, mut source) =
+            load_sender_gas_coin(temporary_store, &top_up.source.0, tx_ctx, protocol_config)?;
+        let (target_obj, target) =
+            load_sender_gas_coin(temporary_store, &top_up.target.0, tx_ctx, protocol_config)?;
+
+        // The amount moved is whatever the target is short of, so a target that already meets
+        // the requested balance would make this a zero-amount transfer.
+        let amount = match top_up.target_balance.checked_sub(target.value()) {
+            Some(amount) if amount > 0 => amount,
+            _ => {
+                return Err(ExecutionError::new_with_source(
+                    ExecutionErrorKind::NativeTopUpTargetAlreadyMet {
+                        balance: target.value(),
+                        target_balance: top_up.target_balance,
+                    },
+                    "Target coin already meets the requested balance",
+                ))
+            }
+        };
+
+        if source.value() < amount {
+            return Err(insufficient_coin_balance(
+                source.value(),
+                amount,
+                "Insufficient coin balance",
+                protocol_config,
+            ));
+        }
+        source.0.balance.withdraw(amount).map_err(|e| {
+            ExecutionError::new_with_source(
+                ExecutionErrorKind::InvalidGasObject,
+                format!("Failed to withdraw: {}", e),
+            )
+        })?;
+
+        let updated_source_obj = Object::new_move(
+            MoveObject::new_gas_coin(source_obj.version(), top_up.source.0, source.value()),
+            source_obj.owner.clone(),
+            tx_ctx.digest(),
+        );
+        let updated_target_obj = Object::new_move(
+            MoveObject::new_gas_coin(target_obj.version(), top_up.target.0, top_up.target_balance),
+            target_obj.owner.clone(),
+            tx_ctx.digest(),
+        );
+        temporary_store.mutate_input_object(updated_source_obj);
+        temporary_store.mutate_input_object(updated_target_obj);
+
+        Ok(())
+    }
+
+    /// Execute a native merge transaction without Move VM and without gas charging
+    fn execute_native_merge(
+        temporary_store: &mut TemporaryStore<'_>,
+        merge: NativeMerge,
+        tx_ctx: &TxContext,
+    ) -> Result<(), ExecutionError> {
+        use sui_types::base_types::ObjectID;
+        use sui_types::gas_coin::GasCoin;
+        use sui_types::object::{MoveObject, Object, Owner};
+
+        // Every input, including the primary coin, must be a gas coin owned by the sender
+        let load_merge_input = |id: &ObjectID| {
+            temporary_store
+                .read_object(id)
+                .filter(|obj| obj.owner == Owner::AddressOwner(tx_ctx.sender()))
+                .and_then(|obj| Some((obj.version(), GasCoin::try_from(obj).ok()?.value())))
+                .ok_or_else(|| {
+                    ExecutionError::new_with_source(
+                        ExecutionErrorKind::InvalidMergeInput { id: *id },
+                        "Merge input must be a gas coin owned by transaction sender",
+                    )
+                })
+        };
+
+        let (primary_version, primary_balance) = load_merge_input(&merge.primary_coin.0)?;
+        let mut merged_balance = primary_balance;
+        for (id, _, _) in &merge.coins_to_merge {
+            let (_, balance) = load_merge_input(id)?;
+            merged_balance = merged_balance.checked_add(balance).ok_or_else(|| {
+                ExecutionError::new_with_source(
+                    ExecutionErrorKind::CoinBalanceOverflow,
+                    "Merged coin balance overflows",
+                )
+            })?;
+        }
+
+        let updated_primary_obj = Object::new_move(
+            MoveObject::new_gas_coin(primary_version, merge.primary_coin.0, merged_balance),
+            Owner::AddressOwner(tx_ctx.sender()),
+            tx_ctx.digest(),
+        );
+        temporary_store.mutate_input_object(updated_primary_obj);
+        for (id, _, _) in &merge.coins_to_merge {
+            temporary_store.delete_input_object(id);
+        }
+
+        Ok(())
+    }
+
+    /// Execute a whole-coin native transfer without Move VM and without gas charging. The coin
+    /// changes owner in place, so no object is created or deleted.
+    fn execute_native_transfer_whole(
+        temporary_store: &mut TemporaryStore<'_>,
+        transfer: NativeTransferWhole,
+        tx_ctx: &TxContext,
+        protocol_config: &ProtocolConfig,
+    ) -> Result<(), ExecutionError> {
+        use sui_types::object::{MoveObject, Object, Owner};
+
+        let (coin_obj, coin) =
+            load_sender_gas_coin(temporary_store, &transfer.coin.0, tx_ctx, protocol_config)?;
+
+        let transferred_coin_obj = Object::new_move(
+            MoveObject::new_gas_coin(coin_obj.version(), transfer.coin.0, coin.value()),
             Owner::AddressOwner(transfer.recipient),
             tx_ctx.digest(),
         );
-        temporary_store.create_object(new_coin_obj);
+        temporary_store.mutate_input_object(transferred_coin_obj);
 
         Ok(())
     }
 
+    /// Execute a native sweep without Move VM and without gas charging. Every coin changes owner
+    /// in place, so no object is created or deleted.
+    fn execute_native_sweep(
+        temporary_store: &mut TemporaryStore<'_>,
+        sweep: NativeSweep,
+        tx_ctx: &TxContext,
+        protocol_config: &ProtocolConfig,
+    ) -> Result<(), ExecutionError> {
+        use sui_types::object::{MoveObject, Object, Owner};
+
+        // Check every coin before writing anything, so a bad coin leaves all of them in place
+        let mut swept_coin_objs = Vec::with_capacity(sweep.coins.len());
+        for (coin_id, _, _) in &sweep.coins {
+            let (coin_obj, coin) =
+                load_sender_gas_coin(temporary_store, coin_id, tx_ctx, protocol_config)?;
+            swept_coin_objs.push(Object::new_move(
+                MoveObject::new_gas_coin(coin_obj.version(), *coin_id, coin.value()),
+                Owner::AddressOwner(sweep.recipient),
+                tx_ctx.digest(),
+            ));
+        }
+        for swept_coin_obj in swept_coin_objs {
+            temporary_store.mutate_input_object(swept_coin_obj);
+        }
+
+        Ok(())
+    }
+
+    /// Execute a native transfer with a change recipient without Move VM and without gas
+    /// charging. The source coin is deleted and its balance split between a new coin for the
+    /// recipient and a new coin for the change recipient.
+    fn execute_native_transfer_with_change(
+        temporary_store: &mut TemporaryStore<'_>,
+        transfer: NativeTransferWithChange,
+        tx_ctx: &mut TxContext,
+        protocol_config: &ProtocolConfig,
+    ) -> Result<(), ExecutionError> {
+        use sui_types::base_types::ObjectID;
+        use sui_types::object::{MoveObject, Object, Owner};
+
+        let (_, coin) =
+            load_sender_gas_coin(temporary_store, &transfer.coin.0, tx_ctx, protocol_config)?;
+
+        let Some(change) = coin.value().checked_sub(transfer.amount) else {
+            return Err(insufficient_coin_balance(
+                coin.value(),
+                transfer.amount,
+                "Insufficient coin balance",
+                protocol_config,
+            ));
+        };
+        if change == 0 {
+            return Err(ExecutionError::new_with_source(
+                ExecutionErrorKind::NativeTransferNoChange {
+                    change_recipient: transfer.change_recipient,
+                },
+                "Transfer leaves no change to send",
+            ));
+        }
+
+        // The change plays the part of the remainder left in the source coin by a plain transfer
+        if let Some(threshold) = protocol_config.native_transfer_dust_threshold_as_option() {
+            if change < threshold {
+                return Err(ExecutionError::new_with_source(
+                    ExecutionErrorKind::NativeTransferDustRemainder {
+                        remainder: change,
+                        threshold,
+                    },
+                    "Change is below the dust threshold",
+                ));
+            }
+        }
+
+        // The recipient's coin is derived first, then the change coin
+        let new_coin_ids: Vec<_> = (0..2).map(|_| ObjectID::from(tx_ctx.fresh_id())).collect();
+
+        temporary_store.delete_input_object(&transfer.coin.0);
+        for ((owner, value), new_coin_id) in [
+            (transfer.recipient, transfer.amount),
+            (transfer.change_recipient, change),
+        ]
+        .into_iter()
+        .zip(new_coin_ids)
+        {
+            let new_coin_obj = Object::new_move(
+                MoveObject::new_gas_coin(
+                    sui_types::base_types::SequenceNumber::MIN,
+                    new_coin_id,
+                    value,
+                ),
+                Owner::AddressOwner(owner),
+                tx_ctx.digest(),
+            );
+            temporary_store.create_object(new_coin_obj);
+        }
+
+        Ok(())
+    }
+
+    /// Read `coin_id` from the store, checking that it is a gas coin owned by the transaction
+    /// sender
+    fn load_sender_gas_coin<'s>(
+        temporary_store: &'s TemporaryStore<'_>,
+        coin_id: &sui_types::base_types::ObjectID,
+        tx_ctx: &TxContext,
+        protocol_config: &ProtocolConfig,
+    ) -> Result<(&'s Object, sui_types::gas_coin::GasCoin), ExecutionError> {
+        load_gas_coin_owned_by(temporary_store, coin_id, tx_ctx.sender(), protocol_config)
+    }
+
+    /// Build the error for a coin that holds less than a native transaction needs
+    fn insufficient_coin_balance(
+        available: u64,
+        requested: u64,
+        message: &str,
+        protocol_config: &ProtocolConfig,
+    ) -> ExecutionError {
+        let kind = if protocol_config.native_insufficient_balance_status() {
+            ExecutionErrorKind::InsufficientCoinBalanceForTransfer {
+                available,
+                requested,
+            }
+        } else {
+            ExecutionErrorKind::InsufficientCoinBalance
+        };
+        ExecutionError::new_with_source(kind, message.to_string())
+    }
+
+    fn load_gas_coin_owned_by<'s>(
+        temporary_store: &'s TemporaryStore<'_>,
+        coin_id: &sui_types::base_types::ObjectID,
+        expected_owner: SuiAddress,
+        protocol_config: &ProtocolConfig,
+    ) -> Result<(&'s Object, sui_types::gas_coin::GasCoin), ExecutionError> {
+        use sui_types::gas_coin::GasCoin;
+        use sui_types::object::Owner;
+
+        let coin_obj = temporary_store.read_object(coin_id).ok_or_else(|| {
+            ExecutionError::new_with_source(
+                ExecutionErrorKind::InvalidGasObject,
+                "Coin object not found",
+            )
+        })?;
+
+        match &coin_obj.owner {
+            Owner::AddressOwner(owner) if *owner == expected_owner => {}
+            _ => {
+                return Err(ExecutionError::new_with_source(
+                    ExecutionErrorKind::InvalidGasObject,
+                    format!("Coin must be owned by {}", expected_owner),
+                ))
+            }
+        }
+
+        let coin = GasCoin::try_from(coin_obj).map_err(|e| {
+            let kind = if protocol_config.native_transfer_not_a_coin_status() {
+                ExecutionErrorKind::NativeTransferInputNotACoin {
+                    object_id: *coin_id,
+                }
+            } else {
+                ExecutionErrorKind::InvalidGasObject
+            };
+            ExecutionError::new_with_source(kind, format!("Failed to convert to gas coin: {}", e))
+        })?;
+        Ok((coin_obj, coin))
+    }
+
     fn setup_coin_deny_list_state_create(
         mut builder: ProgrammableTransactionBuilder,
     ) -> ProgrammableTransactionBuilder {
@@ -50,6 +50,7 @@ pub mod checked {
             gas_status: SuiGasStatus,
             protocol_config: &ProtocolConfig,
         ) -> Self {
+            sui_macros::fail_point!("v2-metered-gas-charger-new");
             let gas_model_version = protocol_config.gas_model_version();
             Self {
                 tx_digest,
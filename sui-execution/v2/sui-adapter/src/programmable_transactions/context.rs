@@ -129,6 +129,7 @@ mod checked {
         where
             'a: 'state,
         {
+            sui_macros::fail_point!("v2-execution-context-new");
             let mut linkage_view = LinkageView::new(Box::new(state_view.as_sui_resolver()));
             let mut input_object_map = BTreeMap::new();
             let inputs = inputs